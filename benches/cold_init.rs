@@ -0,0 +1,75 @@
+//! Times the first lookups of many threads racing to load the table, and reports how often the
+//! loader ran:
+//!
+//! ```text
+//! cargo bench --bench cold_init --features tracing
+//! ```
+//!
+//! The table loads once per process, so this measures a single cold start rather than using
+//! criterion's repeated sampling. A warm round with the same threads follows for comparison.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+
+const THREADS: usize = 64;
+
+/// Counts `statuses::load` spans, one of which is opened each time the table is loaded.
+struct CountLoads(Arc<AtomicUsize>);
+
+impl<S: Subscriber> Layer<S> for CountLoads {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+        if attrs.metadata().name() == "statuses::load" {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Releases `THREADS` threads at once into a lookup and returns the time until all finished.
+fn contended_lookups() -> Duration {
+    let barrier = Arc::new(Barrier::new(THREADS + 1));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let barrier = barrier.clone();
+
+            thread::spawn(move || {
+                barrier.wait();
+                statuses::message("404")
+            })
+        })
+        .collect();
+
+    barrier.wait();
+    let start = Instant::now();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    start.elapsed()
+}
+
+fn main() {
+    let loads = Arc::new(AtomicUsize::new(0));
+    let subscriber = tracing_subscriber::registry().with(CountLoads(loads.clone()));
+
+    tracing::subscriber::set_global_default(subscriber).unwrap();
+
+    let cold = contended_lookups();
+    let warm = contended_lookups();
+
+    println!(
+        "cold init, {} concurrent first callers: {:?}",
+        THREADS, cold
+    );
+    println!("warm, {} concurrent callers: {:?}", THREADS, warm);
+    println!("loader ran {} time(s)", loads.load(Ordering::SeqCst));
+
+    assert_eq!(1, loads.load(Ordering::SeqCst));
+}
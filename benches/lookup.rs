@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Both backing stores over the shipped table, looked up the same way in one run. The registry
+/// itself is built with one or the other, picked by the `no_hashmap` feature.
+fn backends(c: &mut Criterion) {
+    let statuses = statuses::builtin_statuses();
+    let hashmap: HashMap<String, &str> = statuses
+        .iter()
+        .map(|status| (status.code.clone(), status.message.as_str()))
+        .collect();
+    let mut slice: Vec<(u16, &str)> = statuses
+        .iter()
+        .filter_map(|status| Some((status.code.parse().ok()?, status.message.as_str())))
+        .collect();
+    slice.sort_unstable_by_key(|(code, _)| *code);
+
+    let mut group = c.benchmark_group("message by code");
+
+    group.bench_function("hashmap", |b| {
+        b.iter(|| hashmap.get(black_box("404")).copied())
+    });
+    group.bench_function("sorted slice", |b| {
+        b.iter(|| {
+            let code: u16 = black_box("404").parse().ok()?;
+            let index = slice.binary_search_by_key(&code, |(code, _)| *code).ok()?;
+
+            Some(slice[index].1)
+        })
+    });
+    group.finish();
+}
+
+fn lookup(c: &mut Criterion) {
+    let backend = if cfg!(feature = "no_hashmap") {
+        "sorted slice"
+    } else {
+        "hashmap"
+    };
+
+    c.bench_function(&format!("message ({})", backend), |b| {
+        b.iter(|| statuses::message(black_box("404")))
+    });
+    c.bench_function(&format!("code ({})", backend), |b| {
+        b.iter(|| statuses::code(black_box("Not Found")))
+    });
+}
+
+fn suggest(c: &mut Criterion) {
+    c.bench_function("suggest_message (near miss)", |b| {
+        b.iter(|| statuses::suggest_message(black_box("Intrnal Server Eror")))
+    });
+    c.bench_function("suggest_message (no match)", |b| {
+        b.iter(|| statuses::suggest_message(black_box("Completely Different")))
+    });
+}
+
+fn complete(c: &mut Criterion) {
+    let statuses = statuses::builtin_statuses();
+
+    c.bench_function("complete_message (trie)", |b| {
+        b.iter(|| statuses::complete_message(black_box("not"), 10))
+    });
+    c.bench_function("complete_message (naive scan)", |b| {
+        b.iter(|| {
+            let prefix = black_box("not");
+
+            statuses
+                .iter()
+                .filter(|status| status.message.to_lowercase().starts_with(prefix))
+                .take(10)
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+fn classes(c: &mut Criterion) {
+    c.bench_function("codes_in_class", |b| {
+        b.iter(|| statuses::codes_in_class(black_box(statuses::StatusClass::ClientError)))
+    });
+    c.bench_function("group_by_class", |b| b.iter(statuses::group_by_class));
+    c.bench_function("present_classes", |b| b.iter(statuses::present_classes));
+}
+
+fn status_lines(c: &mut Criterion) {
+    c.bench_function("cached_status_line", |b| {
+        b.iter(|| statuses::cached_status_line(black_box(200)))
+    });
+    c.bench_function("status line (formatted per call)", |b| {
+        b.iter(|| {
+            let code = black_box(200);
+
+            format!(
+                "{} {}",
+                code,
+                statuses::message_arc(&code.to_string()).unwrap()
+            )
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    backends,
+    lookup,
+    suggest,
+    complete,
+    classes,
+    status_lines
+);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+//! Compares the memory held by the table as `Vec<Status>` and as `Vec<CompactStatus>`:
+//!
+//! ```text
+//! cargo run --example memory
+//! ```
+//!
+//! Heap sizes count string capacities; the shared messages of `CompactStatus` are counted once.
+
+use std::mem::size_of;
+
+use statuses::{CompactStatus, Status};
+
+fn main() {
+    let full: Vec<Status> = statuses::all_codes()
+        .iter()
+        .map(|code| statuses::resolve(code).unwrap())
+        .collect();
+    let compact = statuses::compact_statuses();
+
+    let full_heap: usize = full
+        .iter()
+        .map(|status| {
+            status.code.len()
+                + status.message.len()
+                + status.description.as_deref().map_or(0, str::len)
+                + status.reference.as_deref().map_or(0, str::len)
+                + status.introduced_in.as_deref().map_or(0, str::len)
+                + status
+                    .aliases
+                    .iter()
+                    .chain(&status.tags)
+                    .map(|item| size_of::<String>() + item.len())
+                    .sum::<usize>()
+        })
+        .sum();
+    let compact_heap: usize = compact.iter().map(|status| status.message.len()).sum();
+
+    println!(
+        "Vec<Status>:        {} entries x {} bytes + {} bytes of strings = {} bytes",
+        full.len(),
+        size_of::<Status>(),
+        full_heap,
+        full.len() * size_of::<Status>() + full_heap
+    );
+    println!(
+        "Vec<CompactStatus>: {} entries x {} bytes + {} bytes of strings = {} bytes",
+        compact.len(),
+        size_of::<CompactStatus>(),
+        compact_heap,
+        compact.len() * size_of::<CompactStatus>() + compact_heap
+    );
+}
@@ -0,0 +1,29 @@
+//! Prints the events emitted with the `tracing` feature:
+//!
+//! ```text
+//! cargo run --example tracing --features tracing
+//! ```
+//!
+//! The output looks like:
+//!
+//! ```text
+//! DEBUG statuses::load{source="embedded" count=63}: statuses::registry: close
+//! TRACE statuses::registry: status lookup by code code="404" found=true
+//! Not Found
+//! TRACE statuses::registry: status lookup by message query="Not Found" found=true
+//! 404
+//! ```
+
+use tracing_subscriber::fmt::format::FmtSpan;
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_span_events(FmtSpan::CLOSE)
+        .without_time()
+        .with_ansi(false)
+        .init();
+
+    println!("{}", statuses::message("404"));
+    println!("{}", statuses::code("Not Found"));
+}
@@ -0,0 +1,20 @@
+//! Looks up statuses without any filesystem access, as on `wasm32-unknown-unknown`:
+//!
+//! ```text
+//! rustup target add wasm32-unknown-unknown
+//! cargo build --example wasm --target wasm32-unknown-unknown
+//! ```
+//!
+//! The table is compiled into the crate, so the resulting module needs no data file.
+
+/// Exported for hosts such as a browser or an edge runtime: whether the code is in the table.
+#[no_mangle]
+pub extern "C" fn is_known_status(code: u16) -> bool {
+    statuses::is_valid_code(&code.to_string())
+}
+
+fn main() {
+    assert!(is_known_status(404));
+    assert!(!is_known_status(999));
+    assert_eq!("Not Found", statuses::message("404"));
+}
@@ -0,0 +1,16 @@
+use crate::{registry, Status, StatusError};
+
+/// Serializes the current table with bincode, so it can be loaded later without parsing JSON.
+pub fn to_bincode() -> Result<Vec<u8>, StatusError> {
+    let statuses = registry::read().statuses();
+
+    bincode::serialize(&statuses).map_err(|err| StatusError::InvalidData(err.to_string()))
+}
+
+/// Replaces the current table with one produced by [`to_bincode`].
+pub fn load_from_bincode(bytes: &[u8]) -> Result<(), StatusError> {
+    let statuses: Vec<Status> =
+        bincode::deserialize(bytes).map_err(|err| StatusError::InvalidData(err.to_string()))?;
+
+    registry::install(statuses.into_iter().collect())
+}
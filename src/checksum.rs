@@ -0,0 +1,56 @@
+use sha2::{Digest, Sha256};
+
+use crate::{registry, StatusRegistry};
+
+/// Feeds a field into the hash with its length first, so that no two different tables serialize
+/// to the same bytes.
+fn field(hasher: &mut Sha256, value: &str) {
+    hasher.update((value.len() as u64).to_le_bytes());
+    hasher.update(value.as_bytes());
+}
+
+impl StatusRegistry {
+    /// Hex-encoded SHA-256 of the table's contents. Statuses are hashed in code order, so the
+    /// checksum depends only on the data, not on how it is stored or the order it was loaded in.
+    pub fn checksum(&self) -> String {
+        let mut statuses = self.statuses();
+        let mut hasher = Sha256::new();
+
+        statuses.sort_by(|a, b| {
+            (a.code.parse::<u16>().ok(), &a.code).cmp(&(b.code.parse::<u16>().ok(), &b.code))
+        });
+
+        for status in &statuses {
+            field(&mut hasher, &status.code);
+            field(&mut hasher, &status.message);
+            field(
+                &mut hasher,
+                status.description.as_deref().unwrap_or_default(),
+            );
+            field(&mut hasher, status.reference.as_deref().unwrap_or_default());
+            field(
+                &mut hasher,
+                status.introduced_in.as_deref().unwrap_or_default(),
+            );
+            for list in [&status.aliases, &status.tags] {
+                hasher.update((list.len() as u64).to_le_bytes());
+
+                for item in list {
+                    field(&mut hasher, item);
+                }
+            }
+        }
+
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+/// Hex-encoded SHA-256 of the current table, for checking that every node of a deployment loaded
+/// the same data. Unlike [`DATA_VERSION`](crate::DATA_VERSION), it changes with the content.
+pub fn table_checksum() -> String {
+    registry::read().checksum()
+}
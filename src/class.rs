@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{registry, Status, StatusError};
+
+/// The class of a status code, given by its first digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub enum StatusClass {
+    /// 1xx
+    Informational,
+    /// 2xx
+    Success,
+    /// 3xx
+    Redirection,
+    /// 4xx
+    ClientError,
+    /// 5xx
+    ServerError,
+    /// Application-specific codes from 600 to 999, carrying the leading digit.
+    Custom(u8),
+}
+
+impl StatusClass {
+    /// Classifies a numeric code, returning `None` outside 100–999.
+    pub fn from_code(code: u16) -> Option<StatusClass> {
+        match code {
+            100..=199 => Some(StatusClass::Informational),
+            200..=299 => Some(StatusClass::Success),
+            300..=399 => Some(StatusClass::Redirection),
+            400..=499 => Some(StatusClass::ClientError),
+            500..=599 => Some(StatusClass::ServerError),
+            600..=999 => Some(StatusClass::Custom((code / 100) as u8)),
+            _ => None,
+        }
+    }
+
+    /// The leading digit its codes share, e.g. `4` for [`ClientError`](Self::ClientError).
+    pub fn digit(&self) -> u8 {
+        match self {
+            StatusClass::Informational => 1,
+            StatusClass::Success => 2,
+            StatusClass::Redirection => 3,
+            StatusClass::ClientError => 4,
+            StatusClass::ServerError => 5,
+            StatusClass::Custom(digit) => *digit,
+        }
+    }
+
+    /// Human-readable name, e.g. `"Client Error"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatusClass::Informational => "Informational",
+            StatusClass::Success => "Success",
+            StatusClass::Redirection => "Redirection",
+            StatusClass::ClientError => "Client Error",
+            StatusClass::ServerError => "Server Error",
+            StatusClass::Custom(_) => "Custom",
+        }
+    }
+}
+
+impl fmt::Display for StatusClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The class of a code in the table, or `None` if the code is unknown.
+pub fn class(code: &str) -> Option<StatusClass> {
+    let registry = registry::read();
+    let status = registry.get(code)?;
+
+    StatusClass::from_code(status.code.parse().ok()?)
+}
+
+/// The first digit of a code in the table, e.g. `4` for 404, or `None` if the code is unknown.
+/// Cheaper than [`class`], and convenient as a metrics label such as
+/// `http_requests_total{class="4"}`.
+pub fn class_digit(code: &str) -> Option<u8> {
+    let registry = registry::read();
+    let first = *registry.get(code)?.code.as_bytes().first()?;
+
+    first.is_ascii_digit().then(|| first - b'0')
+}
+
+/// The class of a numeric code by its first digit alone, without requiring the code to be in the
+/// table. Returns `None` outside 100–999. Use [`class`] when table membership matters.
+pub fn class_u16(code: u16) -> Option<StatusClass> {
+    StatusClass::from_code(code)
+}
+
+/// Succeeds only if the code is in the table and belongs to the class, e.g. to guarantee that a
+/// handler answers with a 2xx. Fails with [`StatusError::NotFound`] for unknown codes and with
+/// [`StatusError::ClassMismatch`] naming both classes otherwise.
+pub fn assert_class(code: &str, expected: StatusClass) -> Result<(), StatusError> {
+    let found = class(code).ok_or_else(|| StatusError::NotFound(code.to_string()))?;
+
+    if found != expected {
+        return Err(StatusError::ClassMismatch {
+            code: code.trim().to_string(),
+            expected,
+            found,
+        });
+    }
+
+    Ok(())
+}
+
+/// The numeric codes of the class in the table, sorted.
+pub fn codes_in_class(class: StatusClass) -> Vec<u16> {
+    registry::read()
+        .class_index()
+        .get(&class)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// The classes with at least one code in the table, in natural order, e.g. for showing UI tabs
+/// only for classes that exist.
+pub fn present_classes() -> Vec<StatusClass> {
+    registry::read().class_index().keys().copied().collect()
+}
+
+/// Every status in the table grouped by class, with classes and the statuses within each sorted
+/// by code. Statuses with codes not spelled as a plain number are left out, and so are classes
+/// left without statuses.
+pub fn group_by_class() -> BTreeMap<StatusClass, Vec<Status>> {
+    let registry = registry::read();
+
+    registry
+        .class_index()
+        .iter()
+        .map(|(&class, codes)| {
+            let statuses: Vec<Status> = codes
+                .iter()
+                .filter_map(|code| registry.get(&code.to_string()).cloned())
+                .collect();
+
+            (class, statuses)
+        })
+        .filter(|(_, statuses)| !statuses.is_empty())
+        .collect()
+}
+
+pub fn is_informational(code: &str) -> bool {
+    class(code) == Some(StatusClass::Informational)
+}
+
+pub fn is_success(code: &str) -> bool {
+    class(code) == Some(StatusClass::Success)
+}
+
+pub fn is_redirection(code: &str) -> bool {
+    class(code) == Some(StatusClass::Redirection)
+}
+
+pub fn is_client_error(code: &str) -> bool {
+    class(code) == Some(StatusClass::ClientError)
+}
+
+pub fn is_server_error(code: &str) -> bool {
+    class(code) == Some(StatusClass::ServerError)
+}
+
+/// Whether the code is a client or server error.
+pub fn is_error(code: &str) -> bool {
+    matches!(
+        class(code),
+        Some(StatusClass::ClientError | StatusClass::ServerError)
+    )
+}
@@ -0,0 +1,35 @@
+use crate::{registry, StatusClass, StatusError};
+
+/// SGR color code for a class: cyan for 1xx, green for 2xx, blue for 3xx, yellow for 4xx and red
+/// for 5xx. Custom classes stay uncolored.
+fn ansi_color(class: StatusClass) -> Option<&'static str> {
+    match class {
+        StatusClass::Informational => Some("36"),
+        StatusClass::Success => Some("32"),
+        StatusClass::Redirection => Some("34"),
+        StatusClass::ClientError => Some("33"),
+        StatusClass::ServerError => Some("31"),
+        StatusClass::Custom(_) => None,
+    }
+}
+
+/// Whether the user opted out of colored output with a non-empty `NO_COLOR`
+/// (<https://no-color.org>).
+fn no_color() -> bool {
+    matches!(std::env::var_os("NO_COLOR"), Some(value) if !value.is_empty())
+}
+
+/// The status line of a code, e.g. `"404 Not Found"`, wrapped in an ANSI color for its class.
+/// Plain text is returned when `NO_COLOR` is set.
+pub fn colored_status_line(code: &str) -> Result<String, StatusError> {
+    let status = registry::read()
+        .get(code)
+        .cloned()
+        .ok_or_else(|| StatusError::NotFound(code.to_string()))?;
+    let color = crate::class(code).and_then(ansi_color);
+
+    match color {
+        Some(color) if !no_color() => Ok(format!("\x1b[{}m{}\x1b[0m", color, status)),
+        _ => Ok(status.to_string()),
+    }
+}
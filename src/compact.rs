@@ -0,0 +1,197 @@
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Arc, OnceLock};
+
+use crate::parse::parse_code;
+use crate::{registry, Status, StatusError};
+
+/// The shipped table in compact form, sorted by code. Its messages borrow from the shipped
+/// registry, which lives for the whole process.
+static SHIPPED: OnceLock<Vec<CompactStatus>> = OnceLock::new();
+
+fn shipped() -> &'static [CompactStatus] {
+    SHIPPED.get_or_init(|| {
+        let mut statuses: Vec<CompactStatus> = registry::shipped()
+            .statuses()
+            .iter()
+            .filter_map(|status| {
+                let code = parse_code(&status.code).ok()?;
+                let message = registry::shipped().message(&status.code)?;
+
+                Some(CompactStatus {
+                    code,
+                    message: CompactMessage::Shipped(message),
+                })
+            })
+            .collect();
+
+        statuses.sort_unstable_by_key(|status| status.code);
+        statuses
+    })
+}
+
+/// The shipped spelling of a message, if the code is shipped with exactly this message.
+fn shipped_message(code: u16, message: &str) -> Option<&'static str> {
+    let shipped = shipped();
+    let index = shipped
+        .binary_search_by_key(&code, |status| status.code)
+        .ok()?;
+
+    match shipped[index].message {
+        CompactMessage::Shipped(shipped) if shipped == message => Some(shipped),
+        _ => None,
+    }
+}
+
+/// The message of a [`CompactStatus`]. Messages shipped with the crate are borrowed from the
+/// embedded table; any other message is shared, so copies of a status never copy its message.
+#[derive(Clone)]
+pub enum CompactMessage {
+    /// Borrowed from the table shipped with the crate.
+    Shipped(&'static str),
+    /// Shared with the table, or the status, it was taken from.
+    Shared(Arc<str>),
+}
+
+impl CompactMessage {
+    fn new(code: u16, message: &str, shared: impl FnOnce() -> Arc<str>) -> Self {
+        match shipped_message(code, message) {
+            Some(message) => CompactMessage::Shipped(message),
+            None => CompactMessage::Shared(shared()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            CompactMessage::Shipped(message) => message,
+            CompactMessage::Shared(message) => message,
+        }
+    }
+}
+
+impl Deref for CompactMessage {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for CompactMessage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for CompactMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for CompactMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self)
+    }
+}
+
+impl PartialEq for CompactMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for CompactMessage {}
+
+impl PartialEq<&str> for CompactMessage {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<CompactMessage> for &str {
+    fn eq(&self, other: &CompactMessage) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl std::hash::Hash for CompactMessage {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialOrd for CompactMessage {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CompactMessage {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// A status holding only its numeric code and a shared message, for keeping many statuses in
+/// memory. Unlike [`Status`] it carries only the code and message.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompactStatus {
+    pub code: u16,
+    pub message: CompactMessage,
+}
+
+impl From<CompactStatus> for Status {
+    fn from(status: CompactStatus) -> Self {
+        Status::new(&status.code.to_string(), &status.message)
+    }
+}
+
+/// Fails for codes that are not numeric, which a `u16` cannot hold.
+impl TryFrom<&Status> for CompactStatus {
+    type Error = StatusError;
+
+    fn try_from(status: &Status) -> Result<Self, Self::Error> {
+        let code = parse_code(&status.code)?;
+
+        Ok(CompactStatus {
+            code,
+            message: CompactMessage::new(code, &status.message, || {
+                Arc::from(status.message.as_str())
+            }),
+        })
+    }
+}
+
+/// Every status in the table in compact form, sorted by code. Statuses whose code is not numeric
+/// are skipped.
+pub fn compact_statuses() -> Vec<CompactStatus> {
+    let registry = registry::read();
+
+    registry
+        .statuses()
+        .iter()
+        .filter_map(|status| {
+            let code = parse_code(&status.code).ok()?;
+            let shared = registry.message_arc(&status.code)?;
+
+            Some(CompactStatus {
+                code,
+                message: CompactMessage::new(code, &status.message, || shared),
+            })
+        })
+        .collect()
+}
+
+/// The status line of a code, e.g. `"200 OK"`, for servers that write the same lines over and
+/// over. Each line is formatted once and then handed out as a clone of the same shared string;
+/// changes to the table are picked up on the next call.
+pub fn cached_status_line(code: u16) -> Option<Arc<str>> {
+    let registry = registry::read();
+    let lines = registry.status_lines();
+
+    lines
+        .binary_search_by_key(&code, |(code, _)| *code)
+        .ok()
+        .map(|i| Arc::clone(&lines[i].1))
+}
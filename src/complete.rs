@@ -0,0 +1,77 @@
+use crate::registry::{self, normalize_key};
+use crate::Status;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: Vec<(char, usize)>,
+    /// Statuses whose message starts with the path to this node, in code order.
+    matches: Vec<usize>,
+}
+
+/// Trie over the normalized messages of a table. Every node keeps the statuses below it, so a
+/// completion only walks the prefix and never the rest of the tree.
+#[derive(Debug, Clone)]
+pub(crate) struct PrefixIndex {
+    statuses: Vec<Status>,
+    nodes: Vec<Node>,
+}
+
+impl PrefixIndex {
+    /// Indexes the statuses, which must be sorted by code.
+    pub(crate) fn new(statuses: Vec<Status>) -> Self {
+        let mut nodes = vec![Node::default()];
+
+        for (position, status) in statuses.iter().enumerate() {
+            let mut current = 0;
+
+            nodes[current].matches.push(position);
+
+            for c in normalize_key(&status.message).chars() {
+                current = match nodes[current].children.iter().find(|(edge, _)| *edge == c) {
+                    Some(&(_, child)) => child,
+                    None => {
+                        let next = nodes.len();
+                        nodes[current].children.push((c, next));
+                        nodes.push(Node::default());
+                        next
+                    }
+                };
+
+                nodes[current].matches.push(position);
+            }
+        }
+
+        PrefixIndex { statuses, nodes }
+    }
+
+    fn complete(&self, prefix: &str, limit: usize) -> Vec<Status> {
+        let mut current = 0;
+
+        for c in normalize_key(prefix).chars() {
+            match self.nodes[current]
+                .children
+                .iter()
+                .find(|(edge, _)| *edge == c)
+            {
+                Some(&(_, child)) => current = child,
+                None => return Vec::new(),
+            }
+        }
+
+        self.nodes[current]
+            .matches
+            .iter()
+            .take(limit)
+            .map(|&position| self.statuses[position].clone())
+            .collect()
+    }
+}
+
+/// Up to `limit` statuses whose message starts with the prefix, ordered by code, for
+/// autocompleting messages as they are typed. Case and surrounding whitespace are ignored.
+///
+/// The messages are indexed on first use and the index is kept until the table changes, so each
+/// keystroke costs only the length of the prefix.
+pub fn complete_message(prefix: &str, limit: usize) -> Vec<Status> {
+    registry::read().prefix_index().complete(prefix, limit)
+}
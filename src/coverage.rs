@@ -0,0 +1,87 @@
+use crate::registry;
+
+/// Numeric codes in the table, sorted.
+fn table_codes() -> Vec<u16> {
+    registry::read()
+        .statuses()
+        .iter()
+        .filter_map(|status| status.code.parse().ok())
+        .collect()
+}
+
+/// Codes from `expected` that are not in the table.
+pub fn missing_from(expected: &[u16]) -> Vec<u16> {
+    let codes = table_codes();
+    let mut missing: Vec<u16> = expected
+        .iter()
+        .copied()
+        .filter(|code| codes.binary_search(code).is_err())
+        .collect();
+
+    missing.sort_unstable();
+    missing.dedup();
+
+    missing
+}
+
+/// Codes in the table that are not in `expected`.
+pub fn extra_beyond(expected: &[u16]) -> Vec<u16> {
+    table_codes()
+        .into_iter()
+        .filter(|code| !expected.contains(code))
+        .collect()
+}
+
+/// Codes between `start` and `end`, inclusive, that are not in the table.
+pub fn unassigned_in_range(start: u16, end: u16) -> Vec<u16> {
+    let codes = table_codes();
+
+    (start..=end)
+        .filter(|code| codes.binary_search(code).is_err())
+        .collect()
+}
+
+/// The codes assigned in the IANA HTTP Status Code Registry, sorted. Codes the registry lists as
+/// unused (306, 418) or only temporarily registered are left out.
+const IANA_CODES: &[u16] = &[
+    100, 101, 102, 103, 200, 201, 202, 203, 204, 205, 206, 207, 208, 226, 300, 301, 302, 303, 304,
+    305, 307, 308, 400, 401, 402, 403, 404, 405, 406, 407, 408, 409, 410, 411, 412, 413, 414, 415,
+    416, 417, 421, 422, 423, 424, 425, 426, 428, 429, 431, 451, 500, 501, 502, 503, 504, 505, 506,
+    507, 508, 510, 511,
+];
+
+/// IANA-registered codes that are not in the table.
+pub fn missing_iana_codes() -> Vec<u16> {
+    missing_from(IANA_CODES)
+}
+
+/// The fraction of IANA-registered codes present in the table, from `0.0` to `1.0`, to measure how
+/// complete a custom table is.
+pub fn iana_coverage() -> f64 {
+    let missing = missing_iana_codes().len();
+
+    (IANA_CODES.len() - missing) as f64 / IANA_CODES.len() as f64
+}
+
+/// How a numeric code relates to the table, as returned by [`code_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeStatus {
+    /// The code is in the table.
+    Assigned,
+    /// The code is not in the table but lies in 100–599, so HTTP could assign it.
+    UnassignedInRange,
+    /// The code is not in the table and lies outside 100–599.
+    Invalid,
+}
+
+/// Classifies a numeric code as assigned, unassigned but plausible, or invalid. This tells "not
+/// yet known" apart from "nonsense", which [`is_valid_code`](crate::is_valid_code) cannot.
+pub fn code_status(code: u16) -> CodeStatus {
+    if registry::read().get(&code.to_string()).is_some() {
+        CodeStatus::Assigned
+    } else if (100..=599).contains(&code) {
+        CodeStatus::UnassignedInRange
+    } else {
+        CodeStatus::Invalid
+    }
+}
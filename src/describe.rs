@@ -0,0 +1,122 @@
+use serde::Serialize;
+
+use crate::{
+    class, is_error, is_retryable, registry, suggests_retry_after, StatusClass, StatusError,
+};
+
+/// Everything known about a status, as returned by [`describe`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatusDescription {
+    pub code: u16,
+    pub message: String,
+    pub class: StatusClass,
+    pub is_error: bool,
+    pub is_retryable: bool,
+    pub reference: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Gathers the message, class and flags of a code in one call.
+pub fn describe(code: &str) -> Result<StatusDescription, StatusError> {
+    let status = registry::read()
+        .get(code)
+        .cloned()
+        .ok_or_else(|| StatusError::NotFound(code.to_string()))?;
+    let numeric = status
+        .code
+        .parse()
+        .map_err(|_| StatusError::InvalidCode(status.code.clone()))?;
+    let class = class(code).ok_or_else(|| StatusError::InvalidCode(status.code.clone()))?;
+
+    Ok(StatusDescription {
+        code: numeric,
+        message: status.message,
+        class,
+        is_error: is_error(code),
+        is_retryable: is_retryable(code),
+        reference: status.reference,
+        description: status.description,
+    })
+}
+
+/// Typical cause of the most common codes, used when the table has no description for them.
+fn typical_cause(code: &str) -> Option<&'static str> {
+    let cause = match code {
+        "400" => "the server could not understand the request",
+        "401" => "the request lacks valid authentication credentials",
+        "403" => "the server understood the request but refuses to authorize it",
+        "404" => "the server has nothing at the requested URL",
+        "408" => "the server gave up waiting for the request",
+        "429" => "you've hit a rate limit by sending too many requests",
+        "500" => "the server hit an unexpected condition",
+        "502" => "a gateway or proxy got an invalid response from upstream",
+        "503" => "the server is overloaded or down for maintenance",
+        "504" => "a gateway or proxy timed out waiting for upstream",
+        _ => return None,
+    };
+
+    Some(cause)
+}
+
+/// What a class of codes generally means, for codes without a more specific cause.
+fn class_cause(class: StatusClass) -> &'static str {
+    match class {
+        StatusClass::Informational => "the server is reporting progress before the final response",
+        StatusClass::Success => "the request succeeded",
+        StatusClass::Redirection => "the resource is available at another location",
+        StatusClass::ClientError => {
+            "the request was rejected because of a problem on the client side"
+        }
+        StatusClass::ServerError => "the server failed to fulfil a valid request",
+        StatusClass::Custom(_) => "the code is specific to the application",
+    }
+}
+
+/// What a client should do about a response with the code.
+fn client_action(code: &str, class: StatusClass) -> &'static str {
+    if is_retryable(code) && suggests_retry_after(code) {
+        return "Wait and retry, honoring Retry-After.";
+    }
+
+    if is_retryable(code) {
+        return "Retrying after a short delay may succeed.";
+    }
+
+    match class {
+        StatusClass::Informational => "Wait for the final response.",
+        StatusClass::Success => "No action is needed.",
+        StatusClass::Redirection => "Follow the Location header, if present.",
+        StatusClass::ClientError => "Fix the request before sending it again.",
+        StatusClass::ServerError => "Report the problem to the server's operators.",
+        StatusClass::Custom(_) => "Consult the application's documentation.",
+    }
+}
+
+/// A friendly paragraph about a code for error pages and CLI diagnostics, combining the reason
+/// phrase, the class, the typical cause and what a client should do. For example, 429 explains
+/// as `"429 Too Many Requests (Client Error): you've hit a rate limit by sending too many
+/// requests. Wait and retry, honoring Retry-After."`
+///
+/// The cause is the status description when the table has one, otherwise a built-in cause for
+/// common codes, otherwise what the class generally means.
+pub fn explain(code: &str) -> Result<String, StatusError> {
+    let status = registry::read()
+        .get(code)
+        .cloned()
+        .ok_or_else(|| StatusError::NotFound(code.to_string()))?;
+    let class = class(code).ok_or_else(|| StatusError::InvalidCode(status.code.clone()))?;
+    let cause = match &status.description {
+        Some(description) => description.trim_end_matches('.').to_string(),
+        None => typical_cause(&status.code)
+            .unwrap_or_else(|| class_cause(class))
+            .to_string(),
+    };
+
+    Ok(format!(
+        "{} ({}): {}. {}",
+        status,
+        class,
+        cause,
+        client_action(&status.code, class)
+    ))
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+
+use crate::StatusClass;
+
+/// Errors returned by the fallible parts of the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StatusError {
+    /// The code or message is not in the table.
+    NotFound(String),
+    /// The code is not a valid numeric status code.
+    InvalidCode(String),
+    /// The code is numeric but too large to be a status code.
+    OutOfRange(String),
+    /// A raw status line is not of the form `HTTP/x.y <code> <reason>`.
+    MalformedStatusLine(String),
+    /// Status data could not be encoded or decoded.
+    InvalidData(String),
+    /// A status file could not be read.
+    Io(String),
+    /// Status data is not valid UTF-8. `offset` is the byte where the invalid sequence starts.
+    Encoding { offset: usize },
+    /// A search pattern is not a valid regular expression.
+    InvalidPattern(String),
+    /// The code is not spelled in the canonical three-digit form.
+    NonCanonicalCode(String),
+    /// The global table was [frozen](crate::freeze) and can no longer be changed.
+    Frozen,
+    /// The code exists but belongs to another class than the expected one.
+    ClassMismatch {
+        code: String,
+        expected: StatusClass,
+        found: StatusClass,
+    },
+}
+
+impl fmt::Display for StatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusError::NotFound(input) => write!(f, "status `{}` does not exist", input),
+            StatusError::InvalidCode(code) => write!(f, "`{}` is not a valid status code", code),
+            StatusError::OutOfRange(code) => {
+                write!(f, "`{}` is out of range for a status code", code)
+            }
+            StatusError::MalformedStatusLine(line) => {
+                write!(f, "malformed status line `{}`", line.escape_debug())
+            }
+            StatusError::InvalidData(reason) => write!(f, "invalid status data: {}", reason),
+            StatusError::Io(reason) => write!(f, "could not read status data: {}", reason),
+            StatusError::Encoding { offset } => {
+                write!(f, "status data is not valid UTF-8 at byte {}", offset)
+            }
+            StatusError::InvalidPattern(reason) => write!(f, "invalid pattern: {}", reason),
+            StatusError::NonCanonicalCode(code) => write!(
+                f,
+                "`{}` is not a canonical three-digit status code",
+                code.escape_debug()
+            ),
+            StatusError::Frozen => write!(f, "the status table is frozen"),
+            StatusError::ClassMismatch {
+                code,
+                expected,
+                found,
+            } => write!(f, "status `{}` is {}, expected {}", code, found, expected),
+        }
+    }
+}
+
+impl std::error::Error for StatusError {}
@@ -0,0 +1,25 @@
+/// Every optional feature, paired with whether this build enables it.
+const FEATURES: &[(&str, bool)] = &[
+    ("bincode", cfg!(feature = "bincode")),
+    ("color", cfg!(feature = "color")),
+    ("http", cfg!(feature = "http")),
+    ("json5", cfg!(feature = "json5")),
+    ("jsonschema", cfg!(feature = "jsonschema")),
+    ("no_hashmap", cfg!(feature = "no_hashmap")),
+    ("phonetic", cfg!(feature = "phonetic")),
+    ("regex", cfg!(feature = "regex")),
+    ("serde_json", cfg!(feature = "serde_json")),
+    ("sha2", cfg!(feature = "sha2")),
+    ("tower", cfg!(feature = "tower")),
+    ("tracing", cfg!(feature = "tracing")),
+];
+
+/// The optional Cargo features this build of the crate was compiled with, in alphabetical order.
+/// Useful for diagnosing why a function or behaviour is missing from a build.
+pub fn active_features() -> Vec<&'static str> {
+    FEATURES
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| *name)
+        .collect()
+}
@@ -0,0 +1,29 @@
+use crate::{registry, Status, StatusError};
+
+impl PartialEq<http::StatusCode> for Status {
+    fn eq(&self, other: &http::StatusCode) -> bool {
+        eq_http(&self.code, *other)
+    }
+}
+
+/// Whether a status code string denotes the same code as an [`http::StatusCode`].
+pub fn eq_http(code: &str, status: http::StatusCode) -> bool {
+    code.trim().parse::<u16>() == Ok(status.as_u16())
+}
+
+/// The reason phrase of a code as a header value, e.g. for echoing it into a custom header.
+/// Phrases that `HeaderValue::from_str` rejects, such as ones containing control characters, fail
+/// with [`StatusError::InvalidData`].
+pub fn reason_header_value(code: &str) -> Result<http::HeaderValue, StatusError> {
+    let message = registry::read()
+        .message(code)
+        .map(str::to_string)
+        .ok_or_else(|| StatusError::NotFound(code.to_string()))?;
+
+    http::HeaderValue::from_str(&message).map_err(|_| {
+        StatusError::InvalidData(format!(
+            "reason phrase {:?} is not a valid header value",
+            message
+        ))
+    })
+}
@@ -0,0 +1,75 @@
+use crate::{Status, StatusError, StatusRegistry};
+
+/// Splits a CSV record into fields, honouring double-quoted fields and `""` escapes.
+fn fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+
+    fields.push(field);
+
+    fields
+}
+
+/// Builds a registry from the IANA "HTTP Status Codes" registry CSV, with its `Value`,
+/// `Description` and `Reference` columns.
+///
+/// Range rows such as `104-199,Unassigned,` and placeholder rows such as `306,(Unused),...` are
+/// skipped.
+pub fn from_iana_csv(csv: &str) -> Result<StatusRegistry, StatusError> {
+    let mut lines = csv.lines().filter(|line| !line.trim().is_empty());
+    let header = lines.next().map(fields).unwrap_or_default();
+
+    if header.len() < 3 || header[..3] != ["Value", "Description", "Reference"] {
+        return Err(StatusError::InvalidData(
+            "expected a `Value,Description,Reference` header".to_string(),
+        ));
+    }
+
+    let mut registry = StatusRegistry::new();
+
+    for line in lines {
+        let mut fields = fields(line)
+            .into_iter()
+            .map(|field| field.trim().to_string());
+        let value = fields.next().unwrap_or_default();
+        let description = fields.next().unwrap_or_default();
+        let reference = fields.next().filter(|reference| !reference.is_empty());
+
+        if value.contains('-') || description == "Unassigned" || description.starts_with('(') {
+            continue;
+        }
+
+        if value.len() != 3 || value.parse::<u16>().is_err() {
+            return Err(StatusError::InvalidData(format!(
+                "invalid status code `{}` in IANA CSV",
+                value
+            )));
+        }
+
+        registry.insert(Status {
+            code: value,
+            message: description,
+            description: None,
+            reference,
+            aliases: Vec::new(),
+            tags: Vec::new(),
+            introduced_in: None,
+        });
+    }
+
+    Ok(registry)
+}
@@ -0,0 +1,85 @@
+use serde_json::{Map, Value};
+
+use crate::{registry, Status, StatusError};
+
+impl Status {
+    /// The status as a JSON object, e.g. `{"code": "404", "message": "Not Found"}`. Description,
+    /// reference, aliases, tags and the introducing spec are included when present.
+    pub fn to_value(&self) -> Value {
+        self.object(Value::String(self.code.clone()))
+    }
+
+    /// Like [`to_value`](Self::to_value), but with a numeric code: `{"code": 404, ...}`. Codes that
+    /// are not numbers stay strings.
+    pub fn to_value_numeric(&self) -> Value {
+        let code = self
+            .code
+            .parse::<u16>()
+            .map_or_else(|_| Value::String(self.code.clone()), Value::from);
+
+        self.object(code)
+    }
+
+    fn object(&self, code: Value) -> Value {
+        let mut object = Map::new();
+
+        object.insert("code".to_string(), code);
+        object.insert("message".to_string(), Value::String(self.message.clone()));
+
+        if let Some(description) = &self.description {
+            object.insert(
+                "description".to_string(),
+                Value::String(description.clone()),
+            );
+        }
+
+        if let Some(reference) = &self.reference {
+            object.insert("reference".to_string(), Value::String(reference.clone()));
+        }
+
+        if !self.aliases.is_empty() {
+            object.insert("aliases".to_string(), Value::from(self.aliases.clone()));
+        }
+
+        if !self.tags.is_empty() {
+            object.insert("tags".to_string(), Value::from(self.tags.clone()));
+        }
+
+        if let Some(spec) = &self.introduced_in {
+            object.insert("introduced_in".to_string(), Value::String(spec.clone()));
+        }
+
+        Value::Object(object)
+    }
+}
+
+impl From<&Status> for Value {
+    fn from(status: &Status) -> Self {
+        status.to_value()
+    }
+}
+
+/// The table as one JSON object mapping codes to messages, e.g.
+/// `{"100":"Continue",...,"404":"Not Found",...}`, the shape front-end code can index directly.
+/// Keys are in numeric order so the output is stable.
+pub fn to_json_map() -> Result<String, StatusError> {
+    let mut json = String::from("{");
+
+    for (i, status) in registry::read().statuses().iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+
+        json.push_str(&serde_json::to_string(&status.code).map_err(invalid)?);
+        json.push(':');
+        json.push_str(&serde_json::to_string(&status.message).map_err(invalid)?);
+    }
+
+    json.push('}');
+
+    Ok(json)
+}
+
+fn invalid(err: serde_json::Error) -> StatusError {
+    StatusError::InvalidData(err.to_string())
+}
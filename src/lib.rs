@@ -1,28 +1,329 @@
-use gjson;
+#[macro_use]
+mod trace;
 
-fn parse(search: &str) -> String {
-    let json = std::fs::read_to_string("codes.json").unwrap();
-    let binding = gjson::get(&json, &search);
+#[cfg(feature = "bincode")]
+mod binary;
+#[cfg(feature = "sha2")]
+mod checksum;
+mod class;
+#[cfg(feature = "color")]
+mod color;
+mod compact;
+mod complete;
+mod coverage;
+mod describe;
+mod error;
+mod features;
+#[cfg(feature = "http")]
+mod http_status;
+mod iana;
+#[cfg(feature = "serde_json")]
+mod json;
+mod load;
+mod lookup;
+mod map;
+mod parse;
+#[cfg(feature = "phonetic")]
+mod phonetic;
+mod reason;
+#[cfg(feature = "regex")]
+mod regex_search;
+mod registry;
+mod render;
+#[cfg(feature = "jsonschema")]
+mod schema;
+mod semantics;
+pub mod serde;
+mod source;
+mod spelling;
+mod status;
+mod status_line;
+mod suggest;
+#[cfg(feature = "tower")]
+mod tower_compat;
+#[cfg(not(target_arch = "wasm32"))]
+mod validate;
 
-    if !binding.exists() {
-        panic!("Status code or message does not exist!");
+#[cfg(feature = "bincode")]
+pub use binary::{load_from_bincode, to_bincode};
+#[cfg(feature = "sha2")]
+pub use checksum::table_checksum;
+pub use class::{
+    assert_class, class, class_digit, class_u16, codes_in_class, group_by_class, is_client_error,
+    is_error, is_informational, is_redirection, is_server_error, is_success, present_classes,
+    StatusClass,
+};
+#[cfg(feature = "color")]
+pub use color::colored_status_line;
+pub use compact::{cached_status_line, compact_statuses, CompactMessage, CompactStatus};
+pub use complete::complete_message;
+pub use coverage::{
+    code_status, extra_beyond, iana_coverage, missing_from, missing_iana_codes,
+    unassigned_in_range, CodeStatus,
+};
+pub use describe::{describe, explain, StatusDescription};
+pub use error::StatusError;
+pub use features::active_features;
+#[cfg(feature = "http")]
+pub use http_status::{eq_http, reason_header_value};
+pub use iana::from_iana_csv;
+#[cfg(feature = "serde_json")]
+pub use json::to_json_map;
+#[cfg(not(target_arch = "wasm32"))]
+pub use load::load_layered;
+pub use load::load_strict_json_str;
+pub use lookup::{GlobalRegistry, StatusLookup};
+pub use parse::{code_from_variant_name, coerce_code, parse_status_line_bytes};
+#[cfg(feature = "phonetic")]
+pub use phonetic::suggest_message_phonetic;
+pub use reason::{
+    canonical_reason_phrase, default_reason, generic_reason, is_header_safe_reason,
+    message_title_case, set_class_fallback, set_unknown_reason,
+};
+#[cfg(feature = "regex")]
+pub use regex_search::find_by_message_regex;
+pub use registry::{
+    builtin_statuses, canonicalize_message, custom_statuses, debug_normalize, freeze, is_bijective,
+    is_frozen, register, register_all, register_returning, StatusRegistry,
+};
+pub use render::{generate_match_arms, html_table, markdown_table, to_env_lines, tree_view};
+#[cfg(feature = "jsonschema")]
+pub use schema::{validate_against_schema, SCHEMA};
+pub use semantics::{
+    allows_body, forbids_content_length, http3_relevant, is_auth_related, is_cacheable_by_default,
+    is_deprecated, is_permanent_redirect, is_retryable, is_safe_to_replay, is_temporary_redirect,
+    log_level, mandatory_client_codes, retry_equivalent, semantics, suggests_retry_after,
+    to_exit_code, Semantics,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use source::FileSource;
+pub use source::{init_from_source, CodeSource, EmbeddedSource, JsonSource};
+pub use spelling::code_spelling_tolerant;
+pub use status::{Status, StatusBuilder};
+pub use status_line::{as_status_line, StatusLine};
+pub use suggest::suggest_message;
+#[cfg(feature = "tower")]
+pub use tower_compat::status_from_code;
+#[cfg(not(target_arch = "wasm32"))]
+pub use validate::{validate_file, ValidationReport};
+
+/// Version of the status table shipped with the crate. The table is versioned with the crate, so
+/// this is the crate version.
+pub const DATA_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Returns [`DATA_VERSION`], e.g. for logging which table a deployment runs.
+pub fn data_version() -> &'static str {
+    DATA_VERSION
+}
+
+/// Whether the code is in the table.
+pub fn is_valid_code(code: &str) -> bool {
+    registry::read().is_valid_code(code)
+}
+
+/// Whether the message, or an alias of it, is in the table. Case is ignored.
+pub fn is_valid_message(message: &str) -> bool {
+    registry::read().is_valid_message(message)
+}
+
+/// Whether the input names both a code and a message in the table, so that [`resolve`] could mean
+/// either. `resolve` takes such input as a code; check this first to handle the overlap
+/// explicitly.
+pub fn is_ambiguous(input: &str) -> bool {
+    registry::read().is_ambiguous(input)
+}
+
+/// The numeric value of a code in the table.
+///
+/// Never panics: inputs that are not plain ASCII digits fail with [`StatusError::InvalidCode`],
+/// numbers too large for a `u16` with [`StatusError::OutOfRange`], and unknown codes with
+/// [`StatusError::NotFound`].
+pub fn code_u16(code: &str) -> Result<u16, StatusError> {
+    let numeric = parse::parse_code(code)?;
+
+    if !is_valid_code(code) {
+        return Err(StatusError::NotFound(code.to_string()));
+    }
+
+    Ok(numeric)
+}
+
+/// The code exactly as stored in the table, or `None` if the code is unknown.
+pub fn canonical_code_string(code: u16) -> Option<String> {
+    registry::read()
+        .get(&code.to_string())
+        .map(|status| status.code.clone())
+}
+
+pub fn code(message: &str) -> String {
+    match registry::read().code(message) {
+        Some(code) => code.to_string(),
+        None => panic!("Status code or message does not exist!"),
+    }
+}
+
+/// Every code in the table whose message matches, sorted by code. Unlike [`code`], this keeps
+/// all of them when several codes share a message.
+pub fn codes_for_message(message: &str) -> Vec<String> {
+    registry::read().codes_for_message(message)
+}
+
+/// The message for a code, like [`message`] but returning an error for unknown codes. When the
+/// code is [deprecated](is_deprecated), `on_deprecated` is first called with a warning such as
+/// `"305 Use Proxy is deprecated"`.
+pub fn message_with_warning(
+    code: &str,
+    on_deprecated: impl FnOnce(&str),
+) -> Result<String, StatusError> {
+    let status = registry::read()
+        .get(code)
+        .cloned()
+        .ok_or_else(|| StatusError::NotFound(code.to_string()))?;
+
+    if is_deprecated(&status.code) {
+        on_deprecated(&format!("{} is deprecated", status));
     }
 
-    let value = binding.to_string();
+    Ok(status.message)
+}
 
-    return value;
+/// The message for a code as a shared string. Every call for the same code returns a clone of the
+/// same allocation, which spares high-throughput servers a `String` per lookup.
+pub fn message_arc(code: &str) -> Result<std::sync::Arc<str>, StatusError> {
+    registry::read()
+        .message_arc(code)
+        .ok_or_else(|| StatusError::NotFound(code.to_string()))
 }
 
-pub fn code(code: &str) -> String {
-    let search = format!("#(message=={}).code", code);
-    let status = parse(&search);
+/// The user-defined tags of a code, or an empty list for unknown codes.
+pub fn tags(code: &str) -> Vec<String> {
+    registry::read().tags(code)
+}
 
-    return status;
+/// Every code in the table carrying the tag, sorted by code.
+pub fn codes_with_tag(tag: &str) -> Vec<String> {
+    registry::read().codes_with_tag(tag)
 }
 
-pub fn message(message: &str) -> String {
-    let search = format!("#(code=={}).message", message);
-    let message = parse(&search);
+/// The message for a code, or `default` for unknown codes.
+pub fn message_or(code: &str, default: &str) -> String {
+    message_or_else(code, |_| default.to_string())
+}
+
+/// The message for a code, or the result of `f` for unknown codes. `f` receives the code with
+/// surrounding whitespace trimmed and is only called when the code is unknown.
+pub fn message_or_else<F: FnOnce(&str) -> String>(code: &str, f: F) -> String {
+    match registry::read().message(code) {
+        Some(message) => message.to_string(),
+        None => f(code.trim()),
+    }
+}
+
+/// Every code in the table first defined by the spec, sorted by code. Codes introduced by older
+/// specs are the safest to use with old clients and servers.
+///
+/// ```
+/// assert_eq!(vec!["428", "429", "431", "511"], statuses::codes_from_spec("RFC 6585"));
+/// ```
+pub fn codes_from_spec(spec: &str) -> Vec<String> {
+    registry::read().codes_from_spec(spec)
+}
+
+pub fn message(code: &str) -> String {
+    match registry::read().message(code) {
+        Some(message) => message.to_string(),
+        None => panic!("Status code or message does not exist!"),
+    }
+}
+
+/// The full status for either a code or a message. Input made only of digits is taken as a code;
+/// anything else is looked up as a message.
+pub fn resolve(input: &str) -> Result<Status, StatusError> {
+    let registry = registry::read();
+    let trimmed = input.trim();
+    let code = if !trimmed.is_empty() && trimmed.bytes().all(|b| b.is_ascii_digit()) {
+        Some(trimmed)
+    } else {
+        registry.code(trimmed)
+    };
+
+    code.and_then(|code| registry.get(code))
+        .cloned()
+        .ok_or_else(|| StatusError::NotFound(input.to_string()))
+}
+
+/// The message for a code spelled exactly in canonical form, for validators that must reject
+/// anything else. The accepted grammar is
+///
+/// ```text
+/// code = %x31-39 DIGIT DIGIT   ; "100" to "999", nothing before or after
+/// ```
+///
+/// so leading zeros, whitespace, signs and decimals fail with [`StatusError::NonCanonicalCode`].
+/// Canonical codes that are not in the table fail with [`StatusError::NotFound`].
+pub fn message_strict_numeric(code: &str) -> Result<String, StatusError> {
+    let canonical = matches!(code.as_bytes(), [b'1'..=b'9', b'0'..=b'9', b'0'..=b'9']);
+
+    if !canonical {
+        return Err(StatusError::NonCanonicalCode(code.to_string()));
+    }
+
+    message_opt(code).ok_or_else(|| StatusError::NotFound(code.to_string()))
+}
+
+/// The message for a code exactly as stored, e.g. `"Not Found"` for 404. Lookups ignore case, but
+/// this is the spelling the table holds and [`all_messages`] lists.
+///
+/// Unlike [`message`] it does not copy the message: it returns the shared copy the table keeps,
+/// as [`message_arc`] does, so nothing stays borrowed from the table.
+pub fn stored_message(code: &str) -> Option<std::sync::Arc<str>> {
+    registry::read().message_arc(code)
+}
+
+/// Like [`message`], but returns `None` for unknown codes instead of panicking.
+pub fn message_opt(code: &str) -> Option<String> {
+    registry::read().message(code).map(str::to_string)
+}
+
+/// The code for a message zero-padded to at least `width` characters, e.g. `"0200"` for `"OK"`
+/// at width 4, for aligned tables and logs. Codes already as wide are returned unchanged.
+pub fn code_formatted(message: &str, width: usize) -> Result<String, StatusError> {
+    let code = code_opt(message).ok_or_else(|| StatusError::NotFound(message.to_string()))?;
+
+    Ok(format!("{:0>width$}", code, width = width))
+}
+
+/// Like [`code`], but returns `None` for unknown messages instead of panicking.
+pub fn code_opt(message: &str) -> Option<String> {
+    registry::read().code(message).map(str::to_string)
+}
+
+/// Every code in the table, ordered numerically.
+///
+/// ```
+/// assert!(statuses::all_codes().contains(&"404".to_string()));
+/// ```
+pub fn all_codes() -> Vec<String> {
+    registry::read()
+        .statuses()
+        .into_iter()
+        .map(|status| status.code)
+        .collect()
+}
+
+/// Every message in the table, ordered alphabetically without regard to case.
+///
+/// ```
+/// assert!(statuses::all_messages().contains(&"Not Found".to_string()));
+/// ```
+pub fn all_messages() -> Vec<String> {
+    let mut messages: Vec<String> = registry::read()
+        .statuses()
+        .into_iter()
+        .map(|status| status.message)
+        .collect();
+
+    messages.sort_by_cached_key(|message| (message.to_lowercase(), message.clone()));
 
-    return message;
+    messages
 }
@@ -0,0 +1,200 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+#[cfg(feature = "json5")]
+use serde::Deserialize;
+
+use crate::{registry, Status, StatusError, StatusRegistry};
+
+/// The fields a status entry may have. Parsing ignores any others unless it is strict.
+#[cfg(not(feature = "json5"))]
+const KNOWN_FIELDS: [&str; 7] = [
+    "code",
+    "message",
+    "description",
+    "reference",
+    "aliases",
+    "tags",
+    "introduced_in",
+];
+
+#[cfg(not(feature = "json5"))]
+fn unknown_field(index: usize, field: &str) -> StatusError {
+    StatusError::InvalidData(format!("unknown field `{}` in status {}", field, index))
+}
+
+/// Drops the byte order mark some Windows editors put at the start of UTF-8 files, which JSON
+/// parsers reject.
+fn strip_bom(json: &str) -> &str {
+    json.strip_prefix('\u{FEFF}').unwrap_or(json)
+}
+
+/// Decodes status data, reporting where the first invalid UTF-8 sequence starts.
+pub(crate) fn decode(bytes: &[u8]) -> Result<&str, StatusError> {
+    std::str::from_utf8(bytes)
+        .map(strip_bom)
+        .map_err(|err| StatusError::Encoding {
+            offset: err.valid_up_to(),
+        })
+}
+
+/// Reads and decodes a status file. Targets without a filesystem, such as
+/// `wasm32-unknown-unknown`, leave out everything that reads files.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn read_file(path: &Path) -> Result<String, StatusError> {
+    let bytes = std::fs::read(path).map_err(|err| StatusError::Io(err.to_string()))?;
+
+    decode(&bytes).map(str::to_string)
+}
+
+/// Parses a JSON array of statuses.
+#[cfg(not(feature = "json5"))]
+pub(crate) fn parse_statuses(json: &str) -> Result<Vec<Status>, StatusError> {
+    let json = strip_bom(json);
+    let value = gjson::parse(json);
+
+    if !gjson::valid(json) || value.kind() != gjson::Kind::Array {
+        return Err(StatusError::InvalidData(
+            "expected a JSON array of statuses".to_string(),
+        ));
+    }
+
+    let optional = |entry: &gjson::Value, key: &str| {
+        let value = entry.get(key);
+
+        value.exists().then(|| value.to_string())
+    };
+
+    Ok(value
+        .array()
+        .iter()
+        .map(|entry| Status {
+            code: entry.get("code").to_string(),
+            message: entry.get("message").to_string(),
+            description: optional(entry, "description"),
+            reference: optional(entry, "reference"),
+            aliases: entry
+                .get("aliases")
+                .array()
+                .iter()
+                .map(|alias| alias.to_string())
+                .collect(),
+            tags: entry
+                .get("tags")
+                .array()
+                .iter()
+                .map(|tag| tag.to_string())
+                .collect(),
+            introduced_in: optional(entry, "introduced_in"),
+        })
+        .collect())
+}
+
+/// Parses a JSON5 array of statuses, which also accepts plain JSON as well as comments and
+/// trailing commas.
+#[cfg(feature = "json5")]
+pub(crate) fn parse_statuses(json: &str) -> Result<Vec<Status>, StatusError> {
+    let json = strip_bom(json);
+    json5::from_str(json).map_err(|err| StatusError::InvalidData(err.to_string()))
+}
+
+/// Like [`parse_statuses`], but rejects entries with fields other than [`KNOWN_FIELDS`].
+#[cfg(not(feature = "json5"))]
+pub(crate) fn parse_statuses_strict(json: &str) -> Result<Vec<Status>, StatusError> {
+    let json = strip_bom(json);
+    let statuses = parse_statuses(json)?;
+
+    for (index, entry) in gjson::parse(json).array().iter().enumerate() {
+        let mut unknown = None;
+
+        entry.each(|key, _| {
+            if !KNOWN_FIELDS.contains(&key.str()) {
+                unknown = Some(key.to_string());
+            }
+
+            unknown.is_none()
+        });
+
+        if let Some(field) = unknown {
+            return Err(unknown_field(index, &field));
+        }
+    }
+
+    Ok(statuses)
+}
+
+/// Mirror of [`Status`] that denies unknown fields, so strict parsing does not change how
+/// `Status` itself deserializes.
+#[cfg(feature = "json5")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictStatus {
+    code: String,
+    message: String,
+    description: Option<String>,
+    reference: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    introduced_in: Option<String>,
+}
+
+/// Like [`parse_statuses`], but rejects entries with fields other than those of [`Status`].
+#[cfg(feature = "json5")]
+pub(crate) fn parse_statuses_strict(json: &str) -> Result<Vec<Status>, StatusError> {
+    let json = strip_bom(json);
+    let entries: Vec<StrictStatus> =
+        json5::from_str(json).map_err(|err| StatusError::InvalidData(err.to_string()))?;
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| Status {
+            code: entry.code,
+            message: entry.message,
+            description: entry.description,
+            reference: entry.reference,
+            aliases: entry.aliases,
+            tags: entry.tags,
+            introduced_in: entry.introduced_in,
+        })
+        .collect())
+}
+
+/// Replaces the current table with the statuses in a JSON document, rejecting entries with fields
+/// that [`Status`] does not have. Use it to enforce the schema of data files; the lenient loaders
+/// ignore unknown fields.
+pub fn load_strict_json_str(json: &str) -> Result<(), StatusError> {
+    registry::install(StatusRegistry::from_json_strict(json)?)
+}
+
+/// Names the file an error came from, where the error has room for it.
+#[cfg(not(target_arch = "wasm32"))]
+fn in_file(path: &Path, err: StatusError) -> StatusError {
+    match err {
+        StatusError::Io(reason) => StatusError::Io(format!("{}: {}", path.display(), reason)),
+        StatusError::InvalidData(reason) => {
+            StatusError::InvalidData(format!("{}: {}", path.display(), reason))
+        }
+        err => err,
+    }
+}
+
+/// Replaces the current table with the statuses of several files loaded in order, where later
+/// files override earlier ones for the same code, e.g. a base table followed by
+/// environment-specific overrides. If any file fails to load, the error names it and the table is
+/// left unchanged.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_layered(paths: &[&Path]) -> Result<(), StatusError> {
+    let mut registry = StatusRegistry::new();
+
+    for path in paths {
+        let json = read_file(path).map_err(|err| in_file(path, err))?;
+
+        for status in parse_statuses(&json).map_err(|err| in_file(path, err))? {
+            registry.insert(status);
+        }
+    }
+
+    registry::install(registry)
+}
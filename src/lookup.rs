@@ -0,0 +1,35 @@
+use crate::{registry, StatusRegistry};
+
+/// Lookups shared by every status table, so callers can accept `&dyn StatusLookup` and swap
+/// implementations.
+pub trait StatusLookup {
+    /// The message for a code.
+    fn message(&self, code: &str) -> Option<String>;
+
+    /// The code for a message.
+    fn code(&self, message: &str) -> Option<String>;
+}
+
+/// The global table used by the free functions of this crate.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlobalRegistry;
+
+impl StatusLookup for GlobalRegistry {
+    fn message(&self, code: &str) -> Option<String> {
+        registry::read().message(code).map(str::to_string)
+    }
+
+    fn code(&self, message: &str) -> Option<String> {
+        registry::read().code(message).map(str::to_string)
+    }
+}
+
+impl StatusLookup for StatusRegistry {
+    fn message(&self, code: &str) -> Option<String> {
+        StatusRegistry::message(self, code).map(str::to_string)
+    }
+
+    fn code(&self, message: &str) -> Option<String> {
+        StatusRegistry::code(self, message).map(str::to_string)
+    }
+}
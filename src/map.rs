@@ -0,0 +1,160 @@
+//! Backing store for the registry indexes.
+//!
+//! By default this is a `HashMap`. With the `no_hashmap` feature it is a vector kept sorted by key
+//! and searched with `binary_search_by_key`, which trades constant-factor lookup speed for less
+//! code. Maps keyed by status code keep numeric codes as `u16`, so the table itself is a sorted
+//! `[(u16, Status)]` slice.
+
+#[cfg(not(feature = "no_hashmap"))]
+pub(crate) type Map<V> = std::collections::HashMap<String, V>;
+
+/// A map keyed by normalized status code.
+#[cfg(not(feature = "no_hashmap"))]
+pub(crate) type CodeMap<V> = std::collections::HashMap<String, V>;
+
+#[cfg(feature = "no_hashmap")]
+#[derive(Debug, Clone)]
+pub(crate) struct Map<V> {
+    entries: Vec<(String, V)>,
+}
+
+#[cfg(feature = "no_hashmap")]
+impl<V> Default for Map<V> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "no_hashmap")]
+impl<V> Map<V> {
+    fn position(&self, key: &str) -> Result<usize, usize> {
+        self.entries
+            .binary_search_by_key(&key, |(entry, _)| entry.as_str())
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&V> {
+        let index = self.position(key).ok()?;
+
+        Some(&self.entries[index].1)
+    }
+
+    pub(crate) fn insert(&mut self, key: String, value: V) -> Option<V> {
+        match self.position(&key) {
+            Ok(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            Err(index) => {
+                self.entries.insert(index, (key, value));
+                None
+            }
+        }
+    }
+
+    pub(crate) fn remove(&mut self, key: &str) -> Option<V> {
+        let index = self.position(key).ok()?;
+
+        Some(self.entries.remove(index).1)
+    }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// A map keyed by normalized status code. Codes written as a plain number, such as `"404"`, are
+/// kept sorted as `u16`; anything else, such as `"0404"` or `"abc"`, falls back to a [`Map`].
+#[cfg(feature = "no_hashmap")]
+#[derive(Debug, Clone)]
+pub(crate) struct CodeMap<V> {
+    numeric: Vec<(u16, V)>,
+    other: Map<V>,
+}
+
+#[cfg(feature = "no_hashmap")]
+impl<V> Default for CodeMap<V> {
+    fn default() -> Self {
+        Self {
+            numeric: Vec::new(),
+            other: Map::default(),
+        }
+    }
+}
+
+/// The number a code is written as, if it is written as one without leading zeros. Other codes
+/// stay distinct from it, as they would as `HashMap` keys.
+#[cfg(feature = "no_hashmap")]
+fn numeric(key: &str) -> Option<u16> {
+    let canonical = !key.is_empty()
+        && key.bytes().all(|b| b.is_ascii_digit())
+        && (key.len() == 1 || !key.starts_with('0'));
+
+    if canonical {
+        key.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "no_hashmap")]
+impl<V> CodeMap<V> {
+    fn position(&self, code: u16) -> Result<usize, usize> {
+        self.numeric
+            .binary_search_by_key(&code, |(entry, _)| *entry)
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&V> {
+        match numeric(key) {
+            Some(code) => {
+                let index = self.position(code).ok()?;
+
+                Some(&self.numeric[index].1)
+            }
+            None => self.other.get(key),
+        }
+    }
+
+    pub(crate) fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub(crate) fn insert(&mut self, key: String, value: V) -> Option<V> {
+        let code = match numeric(&key) {
+            Some(code) => code,
+            None => return self.other.insert(key, value),
+        };
+
+        match self.position(code) {
+            Ok(index) => Some(std::mem::replace(&mut self.numeric[index].1, value)),
+            Err(index) => {
+                self.numeric.insert(index, (code, value));
+                None
+            }
+        }
+    }
+
+    pub(crate) fn remove(&mut self, key: &str) -> Option<V> {
+        match numeric(key) {
+            Some(code) => {
+                let index = self.position(code).ok()?;
+
+                Some(self.numeric.remove(index).1)
+            }
+            None => self.other.remove(key),
+        }
+    }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.numeric
+            .iter()
+            .map(|(_, value)| value)
+            .chain(self.other.values())
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.numeric.len() + self.other.len()
+    }
+}
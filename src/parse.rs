@@ -0,0 +1,97 @@
+use crate::{registry, Status, StatusError};
+
+/// Extracts a known status code from messy input such as a log line.
+///
+/// The input is split into runs of ASCII digits. Each run is clamped to its first three digits,
+/// and the first result that is a code present in the table wins. Runs shorter than three digits
+/// are skipped, so the `1.1` of `HTTP/1.1` is never mistaken for a code.
+///
+/// ```
+/// assert_eq!(Some(404), statuses::coerce_code("HTTP/1.1 404 Not Found"));
+/// assert_eq!(None, statuses::coerce_code("no status here"));
+/// ```
+pub fn coerce_code(input: &str) -> Option<u16> {
+    let registry = registry::read();
+
+    input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|run| run.len() >= 3)
+        .map(|run| &run[..3])
+        .find(|code| registry.get(code).is_some())
+        .and_then(|code| code.parse().ok())
+}
+
+/// Lowercase letters and digits of an identifier or phrase, so that `"NOT_FOUND"`, `"NotFound"`
+/// and `"Not Found"` compare equal.
+fn identifier_key(input: &str) -> String {
+    input
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Maps a generated enum variant name back to its code, e.g. `"NotFound"` or `"NOT_FOUND"` to
+/// `"404"`. Case, underscores, spaces, hyphens and apostrophes are ignored when comparing the name
+/// with messages and aliases; when several match, the lowest code wins.
+///
+/// ```
+/// assert_eq!(Some("404".to_string()), statuses::code_from_variant_name("NOT_FOUND"));
+/// ```
+pub fn code_from_variant_name(name: &str) -> Option<String> {
+    let key = identifier_key(name);
+
+    if key.is_empty() {
+        return None;
+    }
+
+    registry::read()
+        .statuses()
+        .into_iter()
+        .find(|status| {
+            std::iter::once(&status.message)
+                .chain(&status.aliases)
+                .any(|message| identifier_key(message) == key)
+        })
+        .map(|status| status.code)
+}
+
+/// Parses a code made only of ASCII digits into a `u16`, without consulting the table.
+///
+/// Signs, non-ASCII digits and other characters are rejected as [`StatusError::InvalidCode`];
+/// numbers that do not fit a `u16` are rejected as [`StatusError::OutOfRange`].
+pub(crate) fn parse_code(input: &str) -> Result<u16, StatusError> {
+    let code = input.trim();
+
+    if code.is_empty() || !code.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(StatusError::InvalidCode(input.to_string()));
+    }
+
+    code.parse()
+        .map_err(|_| StatusError::OutOfRange(input.to_string()))
+}
+
+/// Parses a raw HTTP status line such as `b"HTTP/1.1 200 OK\r\n"` and returns the table entry for
+/// its code. The reason phrase in the line is not checked against the table.
+pub fn parse_status_line_bytes(buf: &[u8]) -> Result<Status, StatusError> {
+    let malformed = || StatusError::MalformedStatusLine(String::from_utf8_lossy(buf).into_owned());
+    let line = std::str::from_utf8(buf).map_err(|_| malformed())?;
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let mut parts = line.splitn(3, ' ');
+
+    let version = parts.next().unwrap_or_default();
+    let code = parts.next().unwrap_or_default();
+
+    if !version.starts_with("HTTP/")
+        || code.len() != 3
+        || !code.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return Err(malformed());
+    }
+
+    registry::read()
+        .get(code)
+        .cloned()
+        .ok_or_else(|| StatusError::NotFound(code.to_string()))
+}
@@ -0,0 +1,30 @@
+use rphonetic::{Encoder, Soundex};
+
+use crate::registry;
+
+/// Soundex code of every word in a phrase.
+fn codes(soundex: &Soundex, phrase: &str) -> Vec<String> {
+    phrase
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|word| !word.is_empty())
+        .map(|word| soundex.encode(word))
+        .collect()
+}
+
+/// Suggests the message that sounds like the input, word by word, using Soundex. This catches
+/// misspellings such as `"Intrnal Servr Eror"` that are far apart by edit distance. When several
+/// messages match, the one with the lowest code wins.
+pub fn suggest_message_phonetic(input: &str) -> Option<String> {
+    let soundex = Soundex::default();
+    let expected = codes(&soundex, input);
+
+    if expected.is_empty() {
+        return None;
+    }
+
+    registry::read()
+        .statuses()
+        .into_iter()
+        .find(|status| codes(&soundex, &status.message) == expected)
+        .map(|status| status.message)
+}
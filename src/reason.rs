@@ -0,0 +1,140 @@
+use crate::parse::parse_code;
+use crate::{registry, StatusClass, StatusError, StatusRegistry};
+
+const UNKNOWN_REASON: &str = "Unknown Status";
+
+/// Longest reason phrase [`is_header_safe_reason`] accepts, in bytes.
+const MAX_SAFE_REASON_LEN: usize = 256;
+
+impl StatusRegistry {
+    /// The phrase for a class as a whole: a fallback set with
+    /// [`set_class_fallback`](Self::set_class_fallback), or the class name such as
+    /// `"Client Error"`. Custom classes have no name and read `"Unknown Status"`, or the phrase
+    /// set with [`set_unknown_reason`](Self::set_unknown_reason).
+    pub fn generic_reason(&self, class: StatusClass) -> String {
+        if let Some(phrase) = self.class_fallback(class) {
+            return phrase.to_string();
+        }
+
+        match class {
+            StatusClass::Custom(_) => self.unknown_reason(),
+            class => class.name().to_string(),
+        }
+    }
+
+    /// A reason phrase for any code, even one missing from the registry. See [`default_reason`].
+    pub fn default_reason(&self, code: &str) -> String {
+        if let Some(message) = self.message(code) {
+            return message.to_string();
+        }
+
+        match parse_code(code).ok().and_then(StatusClass::from_code) {
+            Some(class) => self.generic_reason(class),
+            None => self.unknown_reason(),
+        }
+    }
+
+    fn unknown_reason(&self) -> String {
+        self.configured_unknown_reason()
+            .unwrap_or(UNKNOWN_REASON)
+            .to_string()
+    }
+}
+
+/// Replaces `"Unknown Status"`, the phrase [`generic_reason`] and [`default_reason`] fall back to
+/// when nothing more specific applies, e.g. to brand the catch-all phrase of an application.
+///
+/// The phrase is kept when a new table is loaded, but cannot be set once the table is
+/// [frozen](crate::freeze): this then fails with [`StatusError::Frozen`].
+pub fn set_unknown_reason(phrase: &str) -> Result<(), StatusError> {
+    registry::write_unfrozen()?.set_unknown_reason(phrase);
+
+    Ok(())
+}
+
+/// Overrides the phrase [`generic_reason`] and [`default_reason`] use for a class. Like
+/// [`set_unknown_reason`], it fails with [`StatusError::Frozen`] once the table is
+/// [frozen](crate::freeze).
+pub fn set_class_fallback(class: StatusClass, phrase: &str) -> Result<(), StatusError> {
+    registry::write_unfrozen()?.set_class_fallback(class, phrase);
+
+    Ok(())
+}
+
+/// The phrase for a class as a whole: a fallback set with [`set_class_fallback`], or the class
+/// name such as `"Client Error"`. Custom classes have no name and read `"Unknown Status"`, or the
+/// phrase set with [`set_unknown_reason`].
+pub fn generic_reason(class: StatusClass) -> String {
+    registry::read().generic_reason(class)
+}
+
+/// The phrase shipped with the crate for a code, ignoring whatever the global table has been
+/// given at runtime. Lets callers show the standard phrase next to a customized one.
+pub fn canonical_reason_phrase(code: &str) -> Option<&'static str> {
+    registry::shipped().message(code)
+}
+
+/// A reason phrase for any code, even one missing from the table.
+///
+/// The message of a known code wins, then the phrase for the code's class (see
+/// [`generic_reason`]), then `"Unknown Status"` for input that is not a code at all. A phrase set
+/// with [`set_unknown_reason`] replaces `"Unknown Status"` but never a class fallback.
+pub fn default_reason(code: &str) -> String {
+    registry::read().default_reason(code)
+}
+
+/// Whether the message of a code can be echoed into a status line or header as is: at most 256
+/// bytes of visible ASCII, spaces and tabs. Phrases with CR, LF or other control characters would
+/// allow header injection and fail, as do unknown codes.
+pub fn is_header_safe_reason(code: &str) -> bool {
+    match registry::read().message(code) {
+        Some(message) => {
+            message.len() <= MAX_SAFE_REASON_LEN
+                && message
+                    .bytes()
+                    .all(|b| b.is_ascii_graphic() || b == b' ' || b == b'\t')
+        }
+        None => false,
+    }
+}
+
+/// Articles kept in lower case unless they start the phrase, as in `"I'm a Teapot"`.
+const SMALL_WORDS: &[&str] = &["a", "an", "the"];
+
+/// Upper-cases the first letter of every word and of every part of a hyphenated word.
+fn title_case(phrase: &str) -> String {
+    phrase
+        .split(' ')
+        .enumerate()
+        .map(|(i, word)| {
+            if i > 0 && SMALL_WORDS.contains(&word.to_lowercase().as_str()) {
+                return word.to_lowercase();
+            }
+
+            word.split('-')
+                .map(|part| {
+                    let mut chars = part.chars();
+
+                    match chars.next() {
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                        None => String::new(),
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("-")
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// The message for a code in Title Case, e.g. `"Internal Server Error"` even if a custom table
+/// spells it `"internal server error"`. Every word and every part of a hyphenated word starts
+/// with a capital, except the articles `a`, `an` and `the` after the first word. Other letters
+/// are left alone, so acronyms such as `URI` survive. Unknown codes fail with
+/// [`StatusError::NotFound`].
+pub fn message_title_case(code: &str) -> Result<String, StatusError> {
+    registry::read()
+        .message(code)
+        .map(title_case)
+        .ok_or_else(|| StatusError::NotFound(code.to_string()))
+}
@@ -0,0 +1,16 @@
+use regex::Regex;
+
+use crate::{registry, Status, StatusError};
+
+/// Every status whose message matches the regular expression, sorted by code. Matching is case
+/// sensitive unless the pattern opts out with `(?i)`. Invalid patterns fail with
+/// [`StatusError::InvalidPattern`].
+pub fn find_by_message_regex(pattern: &str) -> Result<Vec<Status>, StatusError> {
+    let regex = Regex::new(pattern).map_err(|err| StatusError::InvalidPattern(err.to_string()))?;
+
+    Ok(registry::read()
+        .statuses()
+        .into_iter()
+        .filter(|status| regex.is_match(&status.message))
+        .collect())
+}
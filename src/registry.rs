@@ -0,0 +1,634 @@
+use std::collections::BTreeMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::complete::PrefixIndex;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::load::read_file;
+use crate::load::{decode, parse_statuses, parse_statuses_strict};
+use crate::map::{CodeMap, Map};
+use crate::parse::parse_code;
+use crate::suggest::SuggestionIndex;
+use crate::{Status, StatusClass, StatusError};
+
+static REGISTRY: OnceLock<RwLock<StatusRegistry>> = OnceLock::new();
+static SHIPPED: OnceLock<StatusRegistry> = OnceLock::new();
+static FROZEN: AtomicBool = AtomicBool::new(false);
+
+/// The status table shipped with the crate, compiled in so the global table never touches the
+/// filesystem. This keeps the crate usable on targets without one, such as
+/// `wasm32-unknown-unknown`.
+pub(crate) const EMBEDDED: &str = include_str!("../codes.json");
+
+/// Key used for lookups: surrounding whitespace is ignored and messages match case-insensitively.
+pub(crate) fn normalize_key(input: &str) -> String {
+    input.trim().to_lowercase()
+}
+
+/// The key an input is looked up by, for diagnosing lookups that miss: the input with surrounding
+/// whitespace trimmed and lowercased. Whitespace inside is kept, so `"Not  Found"` becomes
+/// `"not  found"` and does not match `"Not Found"`.
+pub fn debug_normalize(input: &str) -> String {
+    normalize_key(input)
+}
+
+/// The message of a status followed by its aliases.
+fn spellings(status: &Status) -> impl Iterator<Item = &String> {
+    std::iter::once(&status.message).chain(&status.aliases)
+}
+
+/// An in-memory table of statuses, indexed by code and by message.
+#[derive(Debug, Clone, Default)]
+pub struct StatusRegistry {
+    statuses: CodeMap<Status>,
+    /// Codes per normalized message or alias, in insertion order; several codes may share one.
+    codes: Map<Vec<String>>,
+    /// Shared copy of each message per code, handed out by [`message_arc`](Self::message_arc).
+    shared: CodeMap<Arc<str>>,
+    custom: CodeMap<()>,
+    allowed: Option<Vec<u16>>,
+    /// Phrases set with [`set_class_fallback`](Self::set_class_fallback), at most one per class.
+    class_fallbacks: Vec<(StatusClass, String)>,
+    unknown_reason: Option<String>,
+    index: OnceLock<SuggestionIndex>,
+    prefixes: OnceLock<PrefixIndex>,
+    classes: OnceLock<BTreeMap<StatusClass, Vec<u16>>>,
+    lines: OnceLock<Vec<(u16, Arc<str>)>>,
+}
+
+impl StatusRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a registry from a JSON array of `{"code": ..., "message": ...}` objects. Malformed
+    /// data yields an empty registry; [`from_json_bytes`](Self::from_json_bytes) reports it.
+    pub fn from_json(json: &str) -> Self {
+        parse_statuses(json)
+            .unwrap_or_default()
+            .into_iter()
+            .collect()
+    }
+
+    /// Builds a registry from JSON bytes, rejecting data that is not UTF-8 or not valid JSON.
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<Self, StatusError> {
+        let statuses = parse_statuses(decode(bytes)?)?;
+
+        Ok(statuses.into_iter().collect())
+    }
+
+    /// Builds a registry from JSON, rejecting entries with fields that [`Status`] does not have.
+    /// The other constructors ignore unknown fields.
+    pub fn from_json_strict(json: &str) -> Result<Self, StatusError> {
+        Ok(parse_statuses_strict(json)?.into_iter().collect())
+    }
+
+    /// Builds a registry from a JSON file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, StatusError> {
+        Self::from_json_bytes(read_file(path.as_ref())?.as_bytes())
+    }
+
+    /// Inserts a status, replacing and returning whatever was stored for the same code.
+    pub fn insert(&mut self, status: Status) -> Option<Status> {
+        let code = normalize_key(&status.code);
+        let previous = self.statuses.remove(&code);
+
+        self.custom.remove(&code);
+        self.discard_indexes();
+
+        if let Some(previous) = &previous {
+            for message in spellings(previous) {
+                let message = normalize_key(message);
+
+                if let Some(mut codes) = self.codes.remove(&message) {
+                    codes.retain(|existing| *existing != code);
+
+                    if !codes.is_empty() {
+                        self.codes.insert(message, codes);
+                    }
+                }
+            }
+        }
+
+        for message in spellings(&status) {
+            let message = normalize_key(message);
+            let mut codes = self.codes.remove(&message).unwrap_or_default();
+
+            if !codes.contains(&code) {
+                codes.push(code.clone());
+            }
+            self.codes.insert(message, codes);
+        }
+
+        self.shared
+            .insert(code.clone(), Arc::from(status.message.as_str()));
+        self.statuses.insert(code, status);
+
+        previous
+    }
+
+    /// Like [`insert`](Self::insert), but tags the status as added at runtime rather than shipped
+    /// with the table.
+    pub fn register(&mut self, status: Status) -> Option<Status> {
+        let code = normalize_key(&status.code);
+        let previous = self.insert(status);
+
+        self.custom.insert(code, ());
+
+        previous
+    }
+
+    /// Overrides the phrase [`generic_reason`](Self::generic_reason) and
+    /// [`default_reason`](Self::default_reason) use for a class.
+    pub fn set_class_fallback(&mut self, class: StatusClass, phrase: &str) {
+        self.class_fallbacks
+            .retain(|(existing, _)| *existing != class);
+        self.class_fallbacks.push((class, phrase.to_string()));
+    }
+
+    /// The phrase set for a class with [`set_class_fallback`](Self::set_class_fallback), if any.
+    pub(crate) fn class_fallback(&self, class: StatusClass) -> Option<&str> {
+        self.class_fallbacks
+            .iter()
+            .find(|(existing, _)| *existing == class)
+            .map(|(_, phrase)| phrase.as_str())
+    }
+
+    /// Replaces `"Unknown Status"`, the phrase [`generic_reason`](Self::generic_reason) and
+    /// [`default_reason`](Self::default_reason) fall back to when nothing more specific applies.
+    pub fn set_unknown_reason(&mut self, phrase: &str) {
+        self.unknown_reason = Some(phrase.to_string());
+    }
+
+    /// The phrase set with [`set_unknown_reason`](Self::set_unknown_reason), if any.
+    pub(crate) fn configured_unknown_reason(&self) -> Option<&str> {
+        self.unknown_reason.as_deref()
+    }
+
+    /// Changes the message of an existing code, e.g. to brand `"Not Found"` as `"Page Not Found"`.
+    /// The old message stops resolving to the code; aliases and other metadata are kept. Fails
+    /// with [`StatusError::NotFound`] if the code is not in the registry.
+    pub fn set_message(&mut self, code: &str, message: &str) -> Result<(), StatusError> {
+        let mut status = self
+            .statuses
+            .get(&normalize_key(code))
+            .cloned()
+            .ok_or_else(|| StatusError::NotFound(code.to_string()))?;
+        let custom = self.is_custom(code);
+
+        status.message = message.to_string();
+
+        if custom {
+            self.register(status);
+        } else {
+            self.insert(status);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the status for the code was added with [`register`](Self::register).
+    pub fn is_custom(&self, code: &str) -> bool {
+        self.custom.contains_key(&normalize_key(code))
+    }
+
+    /// Restricts the registry to the given codes. Every lookup treats other codes as unknown,
+    /// even though they remain stored.
+    pub fn with_allowed(mut self, codes: &[u16]) -> Self {
+        let mut allowed = codes.to_vec();
+
+        allowed.sort_unstable();
+        allowed.dedup();
+        self.allowed = Some(allowed);
+        self.discard_indexes();
+
+        self
+    }
+
+    /// A copy of the registry keeping only the statuses accepted by `keep`, along with its allowed
+    /// codes and reason phrases.
+    fn filtered(&self, keep: impl Fn(&Status) -> bool) -> StatusRegistry {
+        let mut registry = StatusRegistry {
+            allowed: self.allowed.clone(),
+            class_fallbacks: self.class_fallbacks.clone(),
+            unknown_reason: self.unknown_reason.clone(),
+            ..StatusRegistry::default()
+        };
+
+        for status in self.statuses.values().filter(|status| keep(status)) {
+            if self.is_custom(&status.code) {
+                registry.register(status.clone());
+            } else {
+                registry.insert(status.clone());
+            }
+        }
+
+        registry
+    }
+
+    /// A registry with only the statuses of the given class.
+    pub fn only_class(&self, class: StatusClass) -> StatusRegistry {
+        self.filtered(|status| {
+            parse_code(&status.code)
+                .ok()
+                .and_then(StatusClass::from_code)
+                == Some(class)
+        })
+    }
+
+    /// A registry without the statuses added with [`register`](Self::register).
+    pub fn only_standard(&self) -> StatusRegistry {
+        self.filtered(|status| !self.is_custom(&status.code))
+    }
+
+    /// A registry without the given codes.
+    pub fn excluding(&self, codes: &[u16]) -> StatusRegistry {
+        self.filtered(|status| parse_code(&status.code).map_or(true, |code| !codes.contains(&code)))
+    }
+
+    /// Whether the allow-list permits the code. Every code is allowed when there is no allow-list.
+    pub fn is_allowed_code(&self, code: &str) -> bool {
+        match &self.allowed {
+            None => true,
+            Some(allowed) => {
+                matches!(parse_code(code), Ok(code) if allowed.binary_search(&code).is_ok())
+            }
+        }
+    }
+
+    /// Whether the input names both a code and a message, e.g. in a table where `"404"` is also the
+    /// message of another code. [`resolve`](crate::resolve) takes such input as a code.
+    pub fn is_ambiguous(&self, input: &str) -> bool {
+        self.is_valid_code(input) && self.is_valid_message(input)
+    }
+
+    /// Whether the code is in the registry and permitted by its allow-list.
+    pub fn is_valid_code(&self, code: &str) -> bool {
+        self.get(code).is_some()
+    }
+
+    /// Whether the message, or an alias of it, belongs to a permitted code in the registry.
+    pub fn is_valid_message(&self, message: &str) -> bool {
+        self.code(message).is_some()
+    }
+
+    fn lookup(&self, key: &str) -> Option<&Status> {
+        self.statuses
+            .get(key)
+            .filter(|status| self.is_allowed_code(&status.code))
+    }
+
+    pub fn get(&self, code: &str) -> Option<&Status> {
+        let status = self.lookup(&normalize_key(code));
+
+        trace!(code, found = status.is_some(), "status lookup by code");
+
+        status
+    }
+
+    pub fn message(&self, code: &str) -> Option<&str> {
+        self.get(code).map(|status| status.message.as_str())
+    }
+
+    /// Like [`message`](Self::message), but returns a clone of the one shared copy stored for the
+    /// code, so repeated lookups do not allocate.
+    pub fn message_arc(&self, code: &str) -> Option<Arc<str>> {
+        let code = normalize_key(code);
+
+        self.lookup(&code)?;
+        self.shared.get(&code).cloned()
+    }
+
+    /// The code for a message or one of its aliases. When several codes share the message, the one
+    /// inserted last wins; [`codes_for_message`](Self::codes_for_message) returns all of them.
+    pub fn code(&self, message: &str) -> Option<&str> {
+        let code = self
+            .codes
+            .get(&normalize_key(message))
+            .and_then(|codes| codes.iter().rev().find_map(|code| self.lookup(code)))
+            .map(|status| status.code.as_str());
+
+        trace!(
+            query = message,
+            found = code.is_some(),
+            "status lookup by message"
+        );
+
+        code
+    }
+
+    /// Every code whose message matches, sorted by code.
+    pub fn codes_for_message(&self, message: &str) -> Vec<String> {
+        let mut codes: Vec<String> = self
+            .codes
+            .get(&normalize_key(message))
+            .into_iter()
+            .flatten()
+            .filter_map(|code| self.lookup(code))
+            .map(|status| status.code.clone())
+            .collect();
+
+        codes.sort_by_key(|code| code.parse::<u16>().unwrap_or(u16::MAX));
+
+        codes
+    }
+
+    /// The tags of a code, or an empty list for unknown codes.
+    pub fn tags(&self, code: &str) -> Vec<String> {
+        self.get(code)
+            .map(|status| status.tags.clone())
+            .unwrap_or_default()
+    }
+
+    /// Every code carrying the tag, sorted by code. Tags match case-insensitively.
+    pub fn codes_with_tag(&self, tag: &str) -> Vec<String> {
+        let tag = normalize_key(tag);
+
+        self.statuses()
+            .into_iter()
+            .filter(|status| status.tags.iter().any(|t| normalize_key(t) == tag))
+            .map(|status| status.code)
+            .collect()
+    }
+
+    /// Every code first defined by the spec, e.g. `"RFC 6585"`, sorted by code. Specs match
+    /// case-insensitively.
+    pub fn codes_from_spec(&self, spec: &str) -> Vec<String> {
+        let spec = normalize_key(spec);
+
+        self.statuses()
+            .into_iter()
+            .filter(|status| {
+                matches!(&status.introduced_in, Some(introduced) if normalize_key(introduced) == spec)
+            })
+            .map(|status| status.code)
+            .collect()
+    }
+
+    /// The message as stored in the table for a case-insensitive message query.
+    pub fn canonical_message(&self, message: &str) -> Option<&str> {
+        let code = self.code(message)?;
+
+        self.message(code)
+    }
+
+    /// Every status in the registry, sorted by code.
+    pub fn statuses(&self) -> Vec<Status> {
+        let mut statuses: Vec<Status> = self
+            .statuses
+            .values()
+            .filter(|status| self.is_allowed_code(&status.code))
+            .cloned()
+            .collect();
+
+        statuses.sort_by_key(|status| status.code.parse::<u16>().unwrap_or(u16::MAX));
+
+        statuses
+    }
+
+    /// Whether every code maps to a message that maps back to the same code. Tables where two
+    /// codes share a message are not bijective.
+    pub fn is_bijective(&self) -> bool {
+        self.statuses().iter().all(|status| {
+            let bijective = self.code(&status.message) == Some(status.code.as_str());
+
+            if !bijective {
+                trace!(code = status.code, "message does not map back to its code");
+            }
+
+            bijective
+        })
+    }
+
+    /// Drops the indexes built on first use, which mutations make stale.
+    fn discard_indexes(&mut self) {
+        self.index = OnceLock::new();
+        self.prefixes = OnceLock::new();
+        self.classes = OnceLock::new();
+        self.lines = OnceLock::new();
+    }
+
+    /// The status line of every numeric code, sorted by code, built on first use. Mutations
+    /// discard it; lines already handed out stay valid until their last clone is dropped.
+    pub(crate) fn status_lines(&self) -> &[(u16, Arc<str>)] {
+        self.lines.get_or_init(|| {
+            self.statuses()
+                .iter()
+                .filter_map(|status| {
+                    let code = status.code.parse().ok()?;
+
+                    Some((code, Arc::from(status.to_string())))
+                })
+                .collect()
+        })
+    }
+
+    /// The numeric codes of each class present, with classes in natural order and codes sorted,
+    /// built on first use. Mutations discard it. Codes not spelled as a plain number, such as
+    /// `"abc"` or `"0404"`, are left out, since looking them up by number would miss.
+    pub(crate) fn class_index(&self) -> &BTreeMap<StatusClass, Vec<u16>> {
+        self.classes.get_or_init(|| {
+            let mut classes: BTreeMap<StatusClass, Vec<u16>> = BTreeMap::new();
+
+            for status in self.statuses.values() {
+                if !self.is_allowed_code(&status.code) {
+                    continue;
+                }
+
+                let code = match status.code.trim().parse::<u16>() {
+                    Ok(code) if code.to_string() == status.code.trim() => code,
+                    _ => continue,
+                };
+
+                if let Some(class) = StatusClass::from_code(code) {
+                    classes.entry(class).or_default().push(code);
+                }
+            }
+
+            for codes in classes.values_mut() {
+                codes.sort_unstable();
+            }
+
+            classes
+        })
+    }
+
+    /// Fuzzy-match index over the messages, built on first use. Mutations discard it.
+    pub(crate) fn suggestion_index(&self) -> &SuggestionIndex {
+        self.index
+            .get_or_init(|| SuggestionIndex::new(&self.statuses()))
+    }
+
+    /// Prefix index over the messages, built on first use. Mutations discard it.
+    pub(crate) fn prefix_index(&self) -> &PrefixIndex {
+        self.prefixes
+            .get_or_init(|| PrefixIndex::new(self.statuses()))
+    }
+
+    pub fn len(&self) -> usize {
+        match self.allowed {
+            None => self.statuses.len(),
+            Some(_) => self.statuses().len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl FromIterator<Status> for StatusRegistry {
+    fn from_iter<I: IntoIterator<Item = Status>>(statuses: I) -> Self {
+        let mut registry = StatusRegistry::new();
+
+        for status in statuses {
+            registry.insert(status);
+        }
+
+        registry
+    }
+}
+
+fn global() -> &'static RwLock<StatusRegistry> {
+    REGISTRY.get_or_init(|| {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "statuses::load",
+            source = "embedded",
+            count = tracing::field::Empty
+        )
+        .entered();
+
+        let registry = StatusRegistry::from_json(EMBEDDED);
+
+        #[cfg(feature = "tracing")]
+        span.record("count", registry.len());
+
+        RwLock::new(registry)
+    })
+}
+
+/// The embedded table as shipped, unaffected by anything registered or loaded at runtime.
+pub(crate) fn shipped() -> &'static StatusRegistry {
+    SHIPPED.get_or_init(|| StatusRegistry::from_json(EMBEDDED))
+}
+
+pub(crate) fn read() -> RwLockReadGuard<'static, StatusRegistry> {
+    global()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Recovers the canonical spelling of a message, e.g. `"Internal Server Error"` for
+/// `"internal server error"`.
+pub fn canonicalize_message(input: &str) -> Option<String> {
+    read().canonical_message(input).map(str::to_string)
+}
+
+/// Replaces the global table, unless it is [frozen](freeze).
+pub(crate) fn install(mut registry: StatusRegistry) -> Result<(), StatusError> {
+    let mut current = write_unfrozen()?;
+
+    // The reason phrases configure the process rather than describe the data, so a new table
+    // keeps them.
+    registry.class_fallbacks = std::mem::take(&mut current.class_fallbacks);
+    registry.unknown_reason = current.unknown_reason.take();
+    *current = registry;
+
+    Ok(())
+}
+
+pub(crate) fn write() -> RwLockWriteGuard<'static, StatusRegistry> {
+    global()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Write access to the global table for mutations a [`freeze`] forbids. The flag is checked under
+/// the lock, so no mutation can slip in while `freeze` runs.
+pub(crate) fn write_unfrozen() -> Result<RwLockWriteGuard<'static, StatusRegistry>, StatusError> {
+    let registry = write();
+
+    if FROZEN.load(Ordering::Acquire) {
+        return Err(StatusError::Frozen);
+    }
+
+    Ok(registry)
+}
+
+/// Makes the global table immutable: from then on [`register`] and the `load_*` and `init_*`
+/// functions fail with [`StatusError::Frozen`]. Call it once configuration is done to rule out
+/// accidental changes at runtime. Freezing cannot be undone for the rest of the process.
+pub fn freeze() {
+    let _registry = write();
+
+    FROZEN.store(true, Ordering::Release);
+}
+
+/// Whether [`freeze`] has been called.
+pub fn is_frozen() -> bool {
+    FROZEN.load(Ordering::Acquire)
+}
+
+/// Adds a status to the global table, overwriting any existing entry for the code. Fails with
+/// [`StatusError::Frozen`] once the table is [frozen](freeze).
+pub fn register(code: &str, message: &str) -> Result<(), StatusError> {
+    register_returning(code, message).map(drop)
+}
+
+/// Like [`register`], but returns the status previously registered for the code, if any.
+pub fn register_returning(code: &str, message: &str) -> Result<Option<Status>, StatusError> {
+    Ok(write_unfrozen()?.register(Status::new(code.trim(), message.trim())))
+}
+
+/// Registers many statuses under a single lock, which is cheaper than calling [`register`] for
+/// each. Entries overwrite existing ones for the same code, as with [`register`], and later
+/// entries win over earlier ones. Returns how many codes were not in the table before, or
+/// [`StatusError::Frozen`] without registering anything once the table is [frozen](freeze).
+pub fn register_all<I: IntoIterator<Item = (String, String)>>(
+    entries: I,
+) -> Result<usize, StatusError> {
+    let mut registry = write_unfrozen()?;
+    let mut added = 0;
+
+    for (code, message) in entries {
+        if registry
+            .register(Status::new(code.trim(), message.trim()))
+            .is_none()
+        {
+            added += 1;
+        }
+    }
+
+    Ok(added)
+}
+
+/// Whether the code and message lookups of the table are exact inverses.
+pub fn is_bijective() -> bool {
+    read().is_bijective()
+}
+
+/// Statuses shipped with the table, sorted by code.
+pub fn builtin_statuses() -> Vec<Status> {
+    let registry = read();
+
+    registry
+        .statuses()
+        .into_iter()
+        .filter(|status| !registry.is_custom(&status.code))
+        .collect()
+}
+
+/// Statuses added at runtime with [`register`], sorted by code.
+pub fn custom_statuses() -> Vec<Status> {
+    let registry = read();
+
+    registry
+        .statuses()
+        .into_iter()
+        .filter(|status| registry.is_custom(&status.code))
+        .collect()
+}
@@ -0,0 +1,166 @@
+use crate::{group_by_class, registry, StatusClass};
+
+/// Name of the class a status code belongs to, e.g. `"Client Error"` for 404.
+fn class_name(code: &str) -> &'static str {
+    code.parse()
+        .ok()
+        .and_then(StatusClass::from_code)
+        .map_or("Unknown", |class| class.name())
+}
+
+/// Escapes characters that would otherwise be interpreted as Markdown inside a table cell.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(c, '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Renders every status as a Markdown table with `Code`, `Message` and `Class` columns, sorted by
+/// code. A `Description` column is added when any entry carries a description.
+pub fn markdown_table() -> String {
+    let statuses = registry::read().statuses();
+    let with_description = statuses.iter().any(|status| status.description.is_some());
+    let mut table = String::new();
+
+    if with_description {
+        table.push_str("| Code | Message | Class | Description |\n");
+        table.push_str("| ---- | ------- | ----- | ----------- |\n");
+    } else {
+        table.push_str("| Code | Message | Class |\n");
+        table.push_str("| ---- | ------- | ----- |\n");
+    }
+
+    for status in &statuses {
+        table.push_str(&format!(
+            "| {} | {} | {} |",
+            status.code,
+            escape_markdown(&status.message),
+            class_name(&status.code)
+        ));
+
+        if with_description {
+            let description = status.description.as_deref().unwrap_or_default();
+            table.push_str(&format!(" {} |", escape_markdown(description)));
+        }
+
+        table.push('\n');
+    }
+
+    table
+}
+
+/// Escapes the characters HTML gives special meaning inside text and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Renders every status as an HTML `<table>` with `Code`, `Message` and `Class` columns, sorted
+/// by code, for reference pages. Each row carries a CSS class for its status class, e.g.
+/// `<tr class="status-4xx">`, so the output can be styled.
+pub fn html_table() -> String {
+    let mut table = String::from(
+        "<table>\n  <thead>\n    <tr><th>Code</th><th>Message</th><th>Class</th></tr>\n  </thead>\n  <tbody>\n",
+    );
+
+    for status in registry::read().statuses() {
+        let digit = status.code.chars().next().filter(char::is_ascii_digit);
+
+        match digit {
+            Some(digit) => table.push_str(&format!("    <tr class=\"status-{}xx\">", digit)),
+            None => table.push_str("    <tr>"),
+        }
+
+        table.push_str(&format!(
+            "<td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape_html(&status.code),
+            escape_html(&status.message),
+            class_name(&status.code)
+        ));
+    }
+
+    table.push_str("  </tbody>\n</table>\n");
+
+    table
+}
+
+/// Renders one Rust match arm per status, like `"404" => "Not Found",`, sorted by code. Build
+/// scripts can wrap the arms in a `match` of their own, adding a wildcard arm.
+pub fn generate_match_arms() -> String {
+    registry::read()
+        .statuses()
+        .iter()
+        .map(|status| format!("{:?} => {:?},\n", status.code, status.message))
+        .collect()
+}
+
+/// Quotes a value for a POSIX shell unless it consists only of characters that need no quoting.
+fn shell_quote(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ',' | '/' | ':'));
+
+    if plain {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+/// Renders one `STATUS_<code>=<message>` line per status, sorted by code, for sourcing into shell
+/// scripts or passing as container environment variables. Messages are single-quoted when they
+/// contain spaces or other characters a shell would interpret, e.g. `STATUS_404='Not Found'`.
+pub fn to_env_lines() -> String {
+    registry::read()
+        .statuses()
+        .iter()
+        .map(|status| format!("STATUS_{}={}\n", status.code, shell_quote(&status.message)))
+        .collect()
+}
+
+/// Renders the table as an indented tree for CLI output: one line per class with its first digit,
+/// followed by its statuses, sorted by code.
+///
+/// ```text
+/// 1xx Informational
+///   100 Continue
+///   101 Switching Protocols
+/// ...
+/// ```
+pub fn tree_view() -> String {
+    let mut tree = String::new();
+
+    for (class, statuses) in group_by_class() {
+        if statuses.is_empty() {
+            continue;
+        }
+
+        tree.push_str(&format!("{}xx {}\n", class.digit(), class));
+
+        for status in statuses {
+            tree.push_str(&format!("  {}\n", status));
+        }
+    }
+
+    tree
+}
@@ -0,0 +1,23 @@
+use crate::StatusError;
+
+/// The JSON Schema describing the format of `codes.json`.
+pub const SCHEMA: &str = include_str!("../codes.schema.json");
+
+/// Checks a status document against [`SCHEMA`], listing every violation in the error.
+pub fn validate_against_schema(json: &str) -> Result<(), StatusError> {
+    let schema: serde_json::Value =
+        serde_json::from_str(SCHEMA).expect("the bundled schema is valid JSON");
+    let validator = jsonschema::validator_for(&schema).expect("the bundled schema is valid");
+    let instance: serde_json::Value =
+        serde_json::from_str(json).map_err(|err| StatusError::InvalidData(err.to_string()))?;
+    let errors: Vec<String> = validator
+        .iter_errors(&instance)
+        .map(|err| format!("{} at `{}`", err, err.instance_path()))
+        .collect();
+
+    if !errors.is_empty() {
+        return Err(StatusError::InvalidData(errors.join("; ")));
+    }
+
+    Ok(())
+}
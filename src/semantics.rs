@@ -0,0 +1,212 @@
+use serde::Serialize;
+
+use crate::{class, is_redirection, StatusClass, StatusError};
+
+/// The properties HTTP assigns to a status code, as returned by [`semantics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Semantics {
+    /// See [`is_retryable`].
+    pub is_retryable: bool,
+    /// See [`allows_body`].
+    pub allows_body: bool,
+    /// See [`is_cacheable_by_default`].
+    pub is_cacheable_by_default: bool,
+    /// Whether the response completes the request; only 1xx responses are provisional.
+    pub is_final: bool,
+    /// `Some(true)` for permanent redirects, `Some(false)` for temporary ones and `None` for
+    /// codes that are neither.
+    pub permanent_redirect: Option<bool>,
+}
+
+/// Whether a `Retry-After` header is conventional for the code.
+///
+/// RFC 9110 defines `Retry-After` for 503 (how long the service is expected to be unavailable),
+/// 413 (when the condition is temporary) and redirects (how long to wait before following), and
+/// RFC 6585 uses it for 429 to signal when the rate limit resets. Of the redirects, only the
+/// permanent 301 and 308 are included, since those are the ones clients cache.
+pub fn suggests_retry_after(code: &str) -> bool {
+    matches!(code.trim(), "301" | "308" | "413" | "429" | "503")
+}
+
+/// Whether a request that failed with the code may succeed if retried: timeouts, rate limiting
+/// and transient server or gateway failures.
+pub fn is_retryable(code: &str) -> bool {
+    matches!(
+        code.trim(),
+        "408" | "425" | "429" | "500" | "502" | "503" | "504"
+    )
+}
+
+/// The level a request logger should use for a response with the code: `"debug"` for 1xx,
+/// `"info"` for 2xx and 3xx, `"warn"` for 4xx and `"error"` for 5xx. Unknown codes log at
+/// `"warn"`.
+pub fn log_level(code: &str) -> &'static str {
+    match class(code) {
+        Some(StatusClass::Informational) => "debug",
+        Some(StatusClass::Success | StatusClass::Redirection) => "info",
+        Some(StatusClass::ServerError) => "error",
+        Some(StatusClass::ClientError | StatusClass::Custom(_)) | None => "warn",
+    }
+}
+
+/// Exit code for a CLI that reports the outcome of an HTTP request with the code: `0` for 1xx,
+/// 2xx and 3xx, `1` for 4xx and `2` for 5xx. Codes missing from the table and codes in custom
+/// classes exit with `3`.
+pub fn to_exit_code(code: &str) -> i32 {
+    match class(code) {
+        Some(StatusClass::Informational | StatusClass::Success | StatusClass::Redirection) => 0,
+        Some(StatusClass::ClientError) => 1,
+        Some(StatusClass::ServerError) => 2,
+        Some(StatusClass::Custom(_)) | None => 3,
+    }
+}
+
+/// Whether the code is a redirect whose target replaces the requested URL for good (301, 308),
+/// so clients should update stored links.
+pub fn is_permanent_redirect(code: &str) -> bool {
+    is_redirection(code) && matches!(code.trim(), "301" | "308")
+}
+
+/// Whether the code is a redirect that applies to this request only (302, 303, 307).
+pub fn is_temporary_redirect(code: &str) -> bool {
+    is_redirection(code) && matches!(code.trim(), "302" | "303" | "307")
+}
+
+/// Whether a response with the code may carry a message body. Per RFC 9110, Section 6.4.1, 1xx,
+/// 204 (No Content) and 304 (Not Modified) responses never do. Unknown codes return `false`.
+pub fn allows_body(code: &str) -> bool {
+    match class(code) {
+        None | Some(StatusClass::Informational) => false,
+        Some(_) => !matches!(code.trim(), "204" | "304"),
+    }
+}
+
+/// Whether a response with the code must not carry a `Content-Length` header. Per RFC 9110,
+/// Section 8.6, a server must not send one with 1xx or 204 (No Content). Unlike
+/// [`allows_body`], 304 is not included: there the header may state the length of the selected
+/// representation. Unknown codes return `false`.
+pub fn forbids_content_length(code: &str) -> bool {
+    match class(code) {
+        Some(StatusClass::Informational) => true,
+        Some(_) => code.trim() == "204",
+        None => false,
+    }
+}
+
+/// Whether the code is deprecated. RFC 9110 deprecates 305 (Use Proxy) for security reasons,
+/// Section 15.4.6, and reserves the former 306 (Switch Proxy) as unused, Section 15.4.7.
+pub fn is_deprecated(code: &str) -> bool {
+    matches!(code.trim(), "305" | "306")
+}
+
+/// Whether the code concerns authentication or authorization, e.g. for middleware that starts a
+/// login flow:
+///
+/// - 401 (Unauthorized) and 407 (Proxy Authentication Required) ask for credentials for the
+///   origin or a proxy.
+/// - 403 (Forbidden) refuses the credentials given, which may call for logging in as someone else.
+/// - 511 (Network Authentication Required) asks to log in to the network, e.g. a captive portal.
+///
+/// These are the codes the shipped table tags `"auth"`, as listed by
+/// [`codes_with_tag`](crate::codes_with_tag).
+pub fn is_auth_related(code: &str) -> bool {
+    matches!(code.trim(), "401" | "403" | "407" | "511")
+}
+
+/// Whether the code matters particularly to servers speaking HTTP/2 or HTTP/3, which multiplex
+/// requests over one connection:
+///
+/// - 100 (Continue) and 103 (Early Hints, RFC 8297) are interim responses that cost little on a
+///   multiplexed stream, which made preloading with 103 practical.
+/// - 421 (Misdirected Request) lets a server refuse a request sent over a connection reused for
+///   another origin (RFC 9110, Section 15.5.20).
+/// - 425 (Too Early) rejects replayable early data sent in a 0-RTT handshake (RFC 8470).
+pub fn http3_relevant(code: &str) -> bool {
+    matches!(code.trim(), "100" | "103" | "421" | "425")
+}
+
+/// Whether a response with the code may be cached without explicit freshness information
+/// (RFC 9110, Section 15.1): 200, 203, 204, 206, 300, 301, 308, 404, 405, 410, 414 and 501.
+pub fn is_cacheable_by_default(code: &str) -> bool {
+    class(code).is_some()
+        && matches!(
+            code.trim(),
+            "200"
+                | "203"
+                | "204"
+                | "206"
+                | "300"
+                | "301"
+                | "308"
+                | "404"
+                | "405"
+                | "410"
+                | "414"
+                | "501"
+        )
+}
+
+/// Gathers the spec-defined properties of a code in one call, for HTTP libraries implementing
+/// conformant behaviour.
+pub fn semantics(code: &str) -> Result<Semantics, StatusError> {
+    let class = class(code).ok_or_else(|| StatusError::NotFound(code.to_string()))?;
+    let permanent_redirect = if is_permanent_redirect(code) {
+        Some(true)
+    } else if is_temporary_redirect(code) {
+        Some(false)
+    } else {
+        None
+    };
+
+    Ok(Semantics {
+        is_retryable: is_retryable(code),
+        allows_body: allows_body(code),
+        is_cacheable_by_default: is_cacheable_by_default(code),
+        is_final: class != StatusClass::Informational,
+        permanent_redirect,
+    })
+}
+
+/// Whether a client may automatically replay a request with the method after it failed with the
+/// code. Unknown methods and codes return `false`.
+///
+/// | Method | Replayed on |
+/// | ------ | ----------- |
+/// | `GET`, `HEAD`, `OPTIONS`, `TRACE`, `PUT`, `DELETE` | any [retryable](is_retryable) code |
+/// | `POST`, `PATCH`, `CONNECT` | 425 and 429, for which the server did not process the request |
+///
+/// Replaying an idempotent method (RFC 9110, Section 9.2.2) has the same effect as sending it
+/// once, so any transient failure may be retried. Other methods may already have taken effect on a
+/// timeout or server error. Methods are case-sensitive, so `"get"` is unknown.
+pub fn is_safe_to_replay(method: &str, code: &str) -> bool {
+    if class(code).is_none() {
+        return false;
+    }
+
+    match method {
+        "GET" | "HEAD" | "OPTIONS" | "TRACE" | "PUT" | "DELETE" => is_retryable(code),
+        "POST" | "PATCH" | "CONNECT" => matches!(code.trim(), "425" | "429"),
+        _ => false,
+    }
+}
+
+/// Whether retry logic treats the two codes the same: both are in the table, share a class and are
+/// either both [retryable](is_retryable) or both not. Useful to check that a proxy remapping codes
+/// preserves retry behaviour.
+pub fn retry_equivalent(a: &str, b: &str) -> bool {
+    let class_a = class(a);
+
+    class_a.is_some() && class_a == class(b) && is_retryable(a) == is_retryable(b)
+}
+
+/// The codes an HTTP client must be prepared to handle, sorted, for conformance testing.
+///
+/// RFC 9110, Section 15 requires clients to understand every class and to treat an unrecognized
+/// code as the `x00` code of its class, so 100, 200, 300, 400 and 500 are the baseline. On top of
+/// those come codes that change how a response is read: 101, after which the connection speaks
+/// another protocol, and 204 and 304, which never have a body (RFC 9112, Section 6.3). The list
+/// also has 401 and 407, whose challenges a client must answer to authenticate (RFC 9110,
+/// Section 11).
+pub fn mandatory_client_codes() -> Vec<u16> {
+    vec![100, 101, 200, 204, 300, 304, 400, 401, 407, 500]
+}
@@ -0,0 +1,37 @@
+//! Helpers for `#[serde(with = "...")]` on numeric status code fields of user structs.
+
+/// For `#[serde(with = "statuses::serde::status_code")]` on a `u16` field. Deserializing fails
+/// for codes that are not in the table.
+pub mod status_code {
+    use ::serde::de::Error;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::registry;
+
+    pub fn serialize<S: Serializer>(code: &u16, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(*code)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u16, D::Error> {
+        let code = u16::deserialize(deserializer)?;
+
+        if registry::read().get(&code.to_string()).is_none() {
+            return Err(D::Error::custom(format!("unknown status code {}", code)));
+        }
+
+        Ok(code)
+    }
+}
+
+/// Like [`status_code`], but accepts any `u16` without consulting the table.
+pub mod status_code_lenient {
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(code: &u16, serializer: S) -> Result<S::Ok, S::Error> {
+        super::status_code::serialize(code, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u16, D::Error> {
+        u16::deserialize(deserializer)
+    }
+}
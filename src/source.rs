@@ -0,0 +1,71 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::load::read_file;
+use crate::load::{decode, parse_statuses};
+use crate::registry::{self, EMBEDDED};
+use crate::{Status, StatusError};
+
+/// Where status data comes from. Implement it to load the table from anywhere, such as a remote
+/// service or a database, then install the result with [`init_from_source`].
+pub trait CodeSource {
+    fn load(&self) -> Result<Vec<Status>, StatusError>;
+}
+
+/// The table compiled into the crate.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmbeddedSource;
+
+impl CodeSource for EmbeddedSource {
+    fn load(&self) -> Result<Vec<Status>, StatusError> {
+        parse_statuses(EMBEDDED)
+    }
+}
+
+/// A JSON data file, read when the source is loaded.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct FileSource {
+    path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileSource {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        FileSource { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CodeSource for FileSource {
+    fn load(&self) -> Result<Vec<Status>, StatusError> {
+        parse_statuses(&read_file(&self.path)?)
+    }
+}
+
+/// JSON data already in memory.
+#[derive(Debug, Clone)]
+pub struct JsonSource {
+    json: Vec<u8>,
+}
+
+impl JsonSource {
+    pub fn new<B: Into<Vec<u8>>>(json: B) -> Self {
+        JsonSource { json: json.into() }
+    }
+}
+
+impl CodeSource for JsonSource {
+    fn load(&self) -> Result<Vec<Status>, StatusError> {
+        parse_statuses(decode(&self.json)?)
+    }
+}
+
+/// Replaces the current table with the statuses the source yields. On error the table is left
+/// unchanged.
+pub fn init_from_source(source: impl CodeSource) -> Result<(), StatusError> {
+    let statuses = source.load()?;
+
+    registry::install(statuses.into_iter().collect())
+}
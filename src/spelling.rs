@@ -0,0 +1,28 @@
+use crate::registry::{self, normalize_key};
+
+/// British spellings of words in reason phrases, with the American spelling the table uses.
+const BRITISH_TO_AMERICAN: &[(&str, &str)] = &[
+    ("authorisation", "authorization"),
+    ("authorised", "authorized"),
+    ("unauthorised", "unauthorized"),
+];
+
+/// Like [`code_opt`](crate::code_opt), but also accepts British spellings of the words in a
+/// message, e.g. `"Unauthorised"` for 401. Reason phrases are standardized in American English;
+/// the alternatives are `authorisation`, `authorised` and `unauthorised`.
+pub fn code_spelling_tolerant(message: &str) -> Option<String> {
+    let key = normalize_key(message);
+    let americanized: Vec<&str> = key
+        .split(' ')
+        .map(|word| {
+            BRITISH_TO_AMERICAN
+                .iter()
+                .find(|(british, _)| *british == word)
+                .map_or(word, |&(_, american)| american)
+        })
+        .collect();
+
+    registry::read()
+        .code(&americanized.join(" "))
+        .map(str::to_string)
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{registry, StatusError};
+
+/// A single entry of the status table. Deserializing ignores unknown fields, so data with extra
+/// metadata still loads; [`load_strict_json_str`](crate::load_strict_json_str) rejects them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Status {
+    pub code: String,
+    pub message: String,
+    pub description: Option<String>,
+    pub reference: Option<String>,
+    /// Alternate spellings of the message that message lookups also accept, e.g.
+    /// `"Non Authoritative Information"` for 203.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// User-defined labels for grouping codes, e.g. `"auth"` or `"rate-limit"`.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The specification that first defined the code, e.g. `"RFC 6585"` for 429. Unlike
+    /// `reference`, which points at the current definition, this does not change when a spec is
+    /// obsoleted.
+    #[serde(default)]
+    pub introduced_in: Option<String>,
+}
+
+impl Status {
+    /// A status with a code and message and no further metadata.
+    pub fn new(code: &str, message: &str) -> Self {
+        Status {
+            code: code.to_string(),
+            message: message.to_string(),
+            description: None,
+            reference: None,
+            aliases: Vec::new(),
+            tags: Vec::new(),
+            introduced_in: None,
+        }
+    }
+
+    pub fn builder() -> StatusBuilder {
+        StatusBuilder::default()
+    }
+}
+
+/// Builds a [`Status`] field by field, starting from [`Status::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct StatusBuilder {
+    code: String,
+    message: String,
+    description: Option<String>,
+    reference: Option<String>,
+    aliases: Vec<String>,
+    tags: Vec<String>,
+    introduced_in: Option<String>,
+}
+
+impl StatusBuilder {
+    pub fn code(mut self, code: &str) -> Self {
+        self.code = code.to_string();
+        self
+    }
+
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn reference(mut self, reference: &str) -> Self {
+        self.reference = Some(reference.to_string());
+        self
+    }
+
+    /// Adds an alternate spelling of the message; may be called repeatedly.
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    /// Adds a tag; may be called repeatedly.
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    pub fn introduced_in(mut self, spec: &str) -> Self {
+        self.introduced_in = Some(spec.to_string());
+        self
+    }
+
+    pub fn build(self) -> Status {
+        Status {
+            code: self.code,
+            message: self.message,
+            description: self.description,
+            reference: self.reference,
+            aliases: self.aliases,
+            tags: self.tags,
+            introduced_in: self.introduced_in,
+        }
+    }
+}
+
+/// Formats the status as a status line without the protocol version, e.g. `404 Not Found`.
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.code, self.message)
+    }
+}
+
+/// Parses `404 Not Found`, the inverse of `Display`. A bare code such as `404` takes its message
+/// from the table.
+impl FromStr for Status {
+    type Err = StatusError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let malformed = || StatusError::MalformedStatusLine(input.to_string());
+        let (code, message) = input
+            .trim()
+            .split_once(' ')
+            .map_or((input.trim(), None), |(code, message)| {
+                (code, Some(message.trim()))
+            });
+
+        if code.len() != 3 || !code.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(malformed());
+        }
+
+        match message {
+            Some(message) if !message.is_empty() => Ok(Status::new(code, message)),
+            _ => registry::read()
+                .get(code)
+                .cloned()
+                .ok_or_else(|| StatusError::NotFound(code.to_string())),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Serializes a [`Status`] as the single string `"404 Not Found"` instead of an object.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Status;
+
+/// A [`Status`] that serializes as its status line, e.g. `"404 Not Found"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatusLine(pub Status);
+
+impl Serialize for StatusLine {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        as_status_line::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusLine {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        as_status_line::deserialize(deserializer).map(StatusLine)
+    }
+}
+
+/// For `#[serde(with = "statuses::as_status_line")]` on a [`Status`] field.
+pub mod as_status_line {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::Status;
+
+    pub fn serialize<S: Serializer>(status: &Status, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(status)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Status, D::Error> {
+        let line = String::deserialize(deserializer)?;
+
+        line.parse().map_err(D::Error::custom)
+    }
+}
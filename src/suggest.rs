@@ -0,0 +1,127 @@
+use crate::registry::{self, normalize_key};
+use crate::Status;
+
+/// Levenshtein distance between two strings, counted in characters.
+fn distance(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    key: Vec<char>,
+    message: String,
+    children: Vec<(usize, usize)>,
+}
+
+/// BK-tree over the normalized messages of a table. Each child edge is labelled with its edit
+/// distance from the parent, so by the triangle inequality a search only has to descend into
+/// edges within the tolerance of the query's distance to the parent.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SuggestionIndex {
+    nodes: Vec<Node>,
+}
+
+impl SuggestionIndex {
+    /// Indexes the statuses in order; when two messages are equally close to a query, the one
+    /// inserted first wins.
+    pub(crate) fn new(statuses: &[Status]) -> Self {
+        let mut index = SuggestionIndex::default();
+
+        for status in statuses {
+            index.insert(status);
+        }
+
+        index
+    }
+
+    fn insert(&mut self, status: &Status) {
+        let key: Vec<char> = normalize_key(&status.message).chars().collect();
+        let mut current = 0;
+
+        if !self.nodes.is_empty() {
+            loop {
+                let d = distance(&key, &self.nodes[current].key);
+
+                if d == 0 {
+                    return;
+                }
+
+                match self.nodes[current]
+                    .children
+                    .iter()
+                    .find(|(edge, _)| *edge == d)
+                {
+                    Some(&(_, child)) => current = child,
+                    None => {
+                        let next = self.nodes.len();
+                        self.nodes[current].children.push((d, next));
+                        break;
+                    }
+                }
+            }
+        }
+
+        self.nodes.push(Node {
+            key,
+            message: status.message.clone(),
+            children: Vec::new(),
+        });
+    }
+
+    /// The message closest to the query, if any is within `tolerance` edits.
+    fn closest(&self, query: &[char], tolerance: usize) -> Option<&str> {
+        let mut best: Option<(usize, usize)> = None;
+        let mut pending = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+
+        while let Some(current) = pending.pop() {
+            let node = &self.nodes[current];
+            let d = distance(query, &node.key);
+
+            if d <= tolerance && !matches!(best, Some(best) if best <= (d, current)) {
+                best = Some((d, current));
+            }
+
+            pending.extend(
+                node.children
+                    .iter()
+                    .filter(|(edge, _)| edge.abs_diff(d) <= tolerance)
+                    .map(|&(_, child)| child),
+            );
+        }
+
+        best.map(|(_, node)| self.nodes[node].message.as_str())
+    }
+}
+
+/// Suggests the message closest to the input by edit distance, for "did you mean" hints after a
+/// failed lookup. Case and surrounding whitespace are ignored, and up to one edit per three
+/// characters of input is tolerated. Ties go to the lowest code.
+///
+/// The messages are indexed on first use and the index is kept until the table changes, so
+/// repeated misses are cheap.
+pub fn suggest_message(input: &str) -> Option<String> {
+    let query: Vec<char> = normalize_key(input).chars().collect();
+    let tolerance = (query.len() / 3).max(1);
+
+    registry::read()
+        .suggestion_index()
+        .closest(&query, tolerance)
+        .map(str::to_string)
+}
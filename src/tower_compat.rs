@@ -0,0 +1,21 @@
+use crate::{code_u16, StatusError};
+
+/// The [`http::StatusCode`] for a code in the table, as tower middleware expects it.
+///
+/// With tower-http, for example, a layer answering every request with 503:
+///
+/// ```
+/// use tower_http::set_status::SetStatusLayer;
+///
+/// let layer = SetStatusLayer::new(statuses::status_from_code("503")?);
+/// # let _ = layer;
+/// # Ok::<(), statuses::StatusError>(())
+/// ```
+///
+/// Fails like [`code_u16`](crate::code_u16) for invalid or unknown codes, and with
+/// [`StatusError::InvalidCode`] for codes `http` does not accept.
+pub fn status_from_code(code: &str) -> Result<http::StatusCode, StatusError> {
+    let numeric = code_u16(code)?;
+
+    http::StatusCode::from_u16(numeric).map_err(|_| StatusError::InvalidCode(code.to_string()))
+}
@@ -0,0 +1,8 @@
+//! Forwards to `tracing::trace!` with the `tracing` feature and expands to nothing without it.
+
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($arg)*);
+    };
+}
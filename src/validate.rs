@@ -0,0 +1,78 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::load::{parse_statuses, read_file};
+use crate::registry::normalize_key;
+use crate::StatusError;
+
+/// Findings from checking a candidate status file with [`validate_file`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationReport {
+    /// Number of entries in the file.
+    pub entries: usize,
+    /// Number of distinct codes.
+    pub unique_codes: usize,
+    /// Codes that appear more than once.
+    pub duplicate_codes: Vec<String>,
+    /// Codes that are not a number between 100 and 599.
+    pub out_of_range_codes: Vec<String>,
+    /// Messages shared by more than one code.
+    pub message_collisions: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Whether the file can be loaded without losing or misclassifying entries.
+    pub fn is_valid(&self) -> bool {
+        self.duplicate_codes.is_empty()
+            && self.out_of_range_codes.is_empty()
+            && self.message_collisions.is_empty()
+    }
+}
+
+/// Checks a candidate `codes.json` without installing it. The file is parsed as the loaders parse
+/// it, so with the `json5` feature it may contain comments and trailing commas.
+pub fn validate_file<P: AsRef<Path>>(path: P) -> Result<ValidationReport, StatusError> {
+    let json = read_file(path.as_ref())?;
+
+    validate_json(&json)
+}
+
+fn validate_json(json: &str) -> Result<ValidationReport, StatusError> {
+    let statuses = parse_statuses(json)?;
+    let mut codes: BTreeMap<String, usize> = BTreeMap::new();
+    let mut messages: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut report = ValidationReport::default();
+
+    for status in statuses {
+        let code = status.code;
+        let message = status.message;
+
+        report.entries += 1;
+
+        if !matches!(code.parse::<u16>(), Ok(100..=599)) {
+            report.out_of_range_codes.push(code.clone());
+        }
+
+        messages
+            .entry(normalize_key(&message))
+            .or_default()
+            .insert(code.clone());
+        *codes.entry(code).or_default() += 1;
+    }
+
+    report.unique_codes = codes.len();
+    report.duplicate_codes = codes
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(code, _)| code)
+        .collect();
+    report.message_collisions = messages
+        .into_iter()
+        .filter(|(_, codes)| codes.len() > 1)
+        .map(|(message, _)| message)
+        .collect();
+
+    Ok(report)
+}
@@ -0,0 +1,182 @@
+//! Generates typed status vocabularies from the table shipped with [`statuses`], checked at
+//! compile time in the crate that invokes the macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Ident, Token, Visibility};
+
+/// What [`codes!`] expands into.
+enum Output {
+    /// One `pub const` per status.
+    Consts,
+    /// One enum with a variant per status.
+    Enum { vis: Visibility, name: Ident },
+}
+
+impl Parse for Output {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Output::Consts);
+        }
+
+        let vis: Visibility = input.parse()?;
+        input.parse::<Token![enum]>()?;
+        let name: Ident = input.parse()?;
+
+        Ok(Output::Enum { vis, name })
+    }
+}
+
+/// The words of a message, e.g. `["Im", "a", "Teapot"]` for `"I'm a Teapot"`. Apostrophes are
+/// dropped and any other non-alphanumeric character separates words.
+fn words(message: &str) -> Vec<String> {
+    message
+        .replace('\'', "")
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `NOT_FOUND` for `"Not Found"`.
+fn const_name(message: &str) -> String {
+    words(message).join("_").to_ascii_uppercase()
+}
+
+/// `NotFound` for `"Not Found"`.
+fn variant_name(message: &str) -> String {
+    words(message)
+        .iter()
+        .map(|word| {
+            let lower = word.to_ascii_lowercase();
+            let mut chars = lower.chars();
+
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// The statuses that can be named: numeric codes whose message makes a valid identifier, sorted
+/// by code. When two messages make the same identifier, the lowest code keeps it.
+fn entries(name: fn(&str) -> String) -> Vec<(Ident, u16, String)> {
+    let mut entries: Vec<(Ident, u16, String)> = Vec::new();
+
+    for status in statuses::builtin_statuses() {
+        let (Ok(code), identifier) = (status.code.parse::<u16>(), name(&status.message)) else {
+            continue;
+        };
+
+        let valid = matches!(identifier.chars().next(), Some(c) if c.is_ascii_alphabetic());
+
+        if valid
+            && !entries
+                .iter()
+                .any(|(existing, _, _)| *existing == identifier)
+        {
+            entries.push((
+                Ident::new(&identifier, Span::call_site()),
+                code,
+                status.message,
+            ));
+        }
+    }
+
+    entries
+}
+
+/// Expands into status constants generated from the table shipped with `statuses`, so a typo in
+/// a status name is a compile error rather than a runtime lookup failure.
+///
+/// Without input it expands into one constant per status, named after its message:
+///
+/// ```
+/// mod http {
+///     statuses_macros::codes!();
+/// }
+///
+/// assert_eq!(404, http::NOT_FOUND);
+/// assert_eq!(418, http::IM_A_TEAPOT);
+/// ```
+///
+/// Given `enum` and a name, optionally with a visibility, it expands into an enum with one
+/// variant per status instead:
+///
+/// ```
+/// statuses_macros::codes!(pub enum HttpStatus);
+///
+/// assert_eq!(404, HttpStatus::NotFound.code());
+/// assert_eq!("Not Found", HttpStatus::NotFound.message());
+/// assert_eq!(Some(HttpStatus::Ok), HttpStatus::from_code(200));
+/// ```
+#[proc_macro]
+pub fn codes(input: TokenStream) -> TokenStream {
+    match parse_macro_input!(input as Output) {
+        Output::Consts => {
+            let consts = entries(const_name)
+                .into_iter()
+                .map(|(name, code, message)| {
+                    let doc = format!("{} {}", code, message);
+
+                    quote! {
+                        #[doc = #doc]
+                        pub const #name: u16 = #code;
+                    }
+                });
+
+            quote! { #(#consts)* }.into()
+        }
+        Output::Enum { vis, name } => {
+            let entries = entries(variant_name);
+            let variants = entries.iter().map(|(variant, code, message)| {
+                let doc = format!("{} {}", code, message);
+
+                quote! {
+                    #[doc = #doc]
+                    #variant = #code,
+                }
+            });
+            let messages = entries
+                .iter()
+                .map(|(variant, _, message)| quote! { #name::#variant => #message, });
+            let codes = entries
+                .iter()
+                .map(|(variant, code, _)| quote! { #code => Some(#name::#variant), });
+
+            quote! {
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+                #[repr(u16)]
+                #vis enum #name {
+                    #(#variants)*
+                }
+
+                impl #name {
+                    /// The numeric status code.
+                    pub const fn code(self) -> u16 {
+                        self as u16
+                    }
+
+                    /// The reason phrase shipped with `statuses`.
+                    pub const fn message(self) -> &'static str {
+                        match self {
+                            #(#messages)*
+                        }
+                    }
+
+                    /// The variant for a numeric code, or `None` if the table has no such code.
+                    pub const fn from_code(code: u16) -> Option<Self> {
+                        match code {
+                            #(#codes)*
+                            _ => None,
+                        }
+                    }
+                }
+            }
+            .into()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#[test]
+fn it_expands_the_table() {
+    let t = trybuild::TestCases::new();
+
+    t.pass("tests/ui/consts.rs");
+    t.pass("tests/ui/enum.rs");
+    t.compile_fail("tests/ui/unknown_status.rs");
+    t.compile_fail("tests/ui/bad_input.rs");
+}
@@ -0,0 +1,3 @@
+statuses_macros::codes!(pub struct HttpStatus);
+
+fn main() {}
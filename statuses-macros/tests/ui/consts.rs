@@ -0,0 +1,14 @@
+mod http {
+    statuses_macros::codes!();
+}
+
+fn main() {
+    assert_eq!(200, http::OK);
+    assert_eq!(203, http::NON_AUTHORITATIVE_INFORMATION);
+    assert_eq!(404, http::NOT_FOUND);
+    assert_eq!(418, http::IM_A_TEAPOT);
+    assert_eq!(
+        Some("404".to_string()),
+        statuses::code_from_variant_name("NOT_FOUND")
+    );
+}
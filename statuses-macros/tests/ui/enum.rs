@@ -0,0 +1,16 @@
+statuses_macros::codes!(pub enum HttpStatus);
+
+fn main() {
+    assert_eq!(404, HttpStatus::NotFound.code());
+    assert_eq!("Not Found", HttpStatus::NotFound.message());
+    assert_eq!("I'm a Teapot", HttpStatus::ImATeapot.message());
+    assert_eq!(Some(HttpStatus::Ok), HttpStatus::from_code(200));
+    assert_eq!(None, HttpStatus::from_code(499));
+
+    for code in statuses::all_codes() {
+        let code: u16 = code.parse().unwrap();
+        let status = HttpStatus::from_code(code).unwrap();
+
+        assert_eq!(statuses::message(&code.to_string()), status.message());
+    }
+}
@@ -0,0 +1,7 @@
+mod http {
+    statuses_macros::codes!();
+}
+
+fn main() {
+    let _ = http::NOT_FOUNDD;
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "bincode")]
+
+#[test]
+fn it_round_trips_through_bincode() {
+    let bytes = statuses::to_bincode().unwrap();
+
+    statuses::load_from_bincode(&bytes).unwrap();
+
+    assert_eq!(bytes, statuses::to_bincode().unwrap());
+    assert_eq!("Not Found", statuses::message("404"));
+    assert_eq!("422", statuses::code("Unprocessable Entity"));
+}
+
+#[test]
+fn it_rejects_invalid_bincode() {
+    assert!(statuses::load_from_bincode(&[0xff; 3]).is_err());
+}
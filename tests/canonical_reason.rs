@@ -0,0 +1,8 @@
+#[test]
+fn it_returns_the_shipped_phrase_of_an_overridden_code() {
+    statuses::register("404", "Page Not Found").unwrap();
+
+    assert_eq!("Page Not Found", statuses::message("404"));
+    assert_eq!(Some("Not Found"), statuses::canonical_reason_phrase("404"));
+    assert_eq!(None, statuses::canonical_reason_phrase("499"));
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "sha2")]
+
+use statuses::{Status, StatusRegistry};
+
+#[test]
+fn it_checksums_identical_data_identically() {
+    let forward: StatusRegistry = vec![Status::new("200", "OK"), Status::new("404", "Not Found")]
+        .into_iter()
+        .collect();
+    let backward: StatusRegistry = vec![Status::new("404", "Not Found"), Status::new("200", "OK")]
+        .into_iter()
+        .collect();
+    let different: StatusRegistry = vec![Status::new("200", "OK"), Status::new("404", "Missing")]
+        .into_iter()
+        .collect();
+
+    assert_eq!(forward.checksum(), backward.checksum());
+    assert_ne!(forward.checksum(), different.checksum());
+    assert_eq!(64, forward.checksum().len());
+}
+
+#[test]
+fn it_checksums_the_table() {
+    let json = std::fs::read_to_string("codes.json").unwrap();
+
+    assert_eq!(
+        StatusRegistry::from_json(&json).checksum(),
+        statuses::table_checksum()
+    );
+}
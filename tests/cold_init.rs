@@ -0,0 +1,52 @@
+#![cfg(feature = "tracing")]
+
+// The table loads once per process, so the first lookup must happen in this test binary's only
+// test.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+
+/// Counts `statuses::load` spans, one of which is opened each time the table is loaded.
+struct CountLoads(Arc<AtomicUsize>);
+
+impl<S: Subscriber> Layer<S> for CountLoads {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+        if attrs.metadata().name() == "statuses::load" {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+#[test]
+fn it_loads_once_under_concurrent_first_lookups() {
+    const THREADS: usize = 32;
+
+    let loads = Arc::new(AtomicUsize::new(0));
+    let subscriber = tracing_subscriber::registry().with(CountLoads(loads.clone()));
+
+    tracing::subscriber::set_global_default(subscriber).unwrap();
+
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let barrier = barrier.clone();
+
+            thread::spawn(move || {
+                barrier.wait();
+                statuses::message("404")
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!("Not Found", handle.join().unwrap());
+    }
+
+    assert_eq!(1, loads.load(Ordering::SeqCst));
+}
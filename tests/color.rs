@@ -0,0 +1,29 @@
+#![cfg(feature = "color")]
+
+// NO_COLOR is process-wide, so both cases run in one test.
+#[test]
+fn it_colors_status_line_unless_no_color() {
+    std::env::remove_var("NO_COLOR");
+
+    assert_eq!(
+        Ok("\x1b[32m200 OK\x1b[0m".to_string()),
+        statuses::colored_status_line("200")
+    );
+    assert_eq!(
+        Ok("\x1b[33m404 Not Found\x1b[0m".to_string()),
+        statuses::colored_status_line("404")
+    );
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("999".to_string())),
+        statuses::colored_status_line("999")
+    );
+
+    std::env::set_var("NO_COLOR", "1");
+
+    assert_eq!(
+        Ok("503 Service Unavailable".to_string()),
+        statuses::colored_status_line("503")
+    );
+
+    std::env::remove_var("NO_COLOR");
+}
@@ -0,0 +1,13 @@
+// Changing the working directory affects the whole process, so this runs in its own test binary.
+
+#[test]
+fn it_looks_up_statuses_without_a_data_file() {
+    let dir = std::env::temp_dir().join("statuses-embedded-test");
+
+    std::fs::create_dir_all(&dir).unwrap();
+    std::env::set_current_dir(&dir).unwrap();
+
+    assert!(!std::path::Path::new("codes.json").exists());
+    assert_eq!("Not Found", statuses::message("404"));
+    assert_eq!("404", statuses::code("Not Found"));
+}
@@ -0,0 +1,23 @@
+#[test]
+fn it_rejects_changes_after_freezing() {
+    statuses::register("299", "Custom Success").unwrap();
+    assert!(!statuses::is_frozen());
+
+    statuses::freeze();
+
+    assert!(statuses::is_frozen());
+    assert_eq!(
+        Err(statuses::StatusError::Frozen),
+        statuses::register("298", "Other Success")
+    );
+    assert_eq!(
+        Err(statuses::StatusError::Frozen),
+        statuses::load_strict_json_str(r#"[{"code": "200", "message": "OK"}]"#)
+    );
+    assert_eq!(
+        Err(statuses::StatusError::Frozen),
+        statuses::init_from_source(statuses::EmbeddedSource)
+    );
+    assert_eq!(None, statuses::message_opt("298"));
+    assert_eq!("Custom Success", statuses::message("299"));
+}
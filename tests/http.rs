@@ -0,0 +1,66 @@
+#![cfg(feature = "http")]
+
+use statuses::Status;
+
+#[test]
+fn it_compares_with_http_status_code() {
+    assert!(statuses::eq_http("200", http::StatusCode::OK));
+    assert!(!statuses::eq_http("200", http::StatusCode::NOT_FOUND));
+}
+
+#[test]
+fn it_compares_status_with_http_status_code() {
+    let status = Status::new("404", "Not Found");
+
+    assert!(status == http::StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn it_converts_reason_phrase_to_header_value() {
+    assert_eq!(
+        Ok(http::HeaderValue::from_static("Not Found")),
+        statuses::reason_header_value("404")
+    );
+
+    statuses::register("644", "Bell\u{7}Ring").unwrap();
+
+    assert!(matches!(
+        statuses::reason_header_value("644"),
+        Err(statuses::StatusError::InvalidData(_))
+    ));
+}
+
+#[cfg(feature = "tower")]
+#[test]
+fn it_converts_code_to_http_status_code() {
+    assert_eq!(
+        Ok(http::StatusCode::SERVICE_UNAVAILABLE),
+        statuses::status_from_code("503")
+    );
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("999".to_string())),
+        statuses::status_from_code("999")
+    );
+    assert_eq!(
+        Err(statuses::StatusError::InvalidCode("abc".to_string())),
+        statuses::status_from_code("abc")
+    );
+}
+
+#[cfg(feature = "tower")]
+#[test]
+fn it_sets_the_status_with_a_tower_http_layer() {
+    use std::convert::Infallible;
+
+    use tower::{service_fn, Layer, ServiceExt};
+    use tower_http::set_status::SetStatusLayer;
+
+    let layer = SetStatusLayer::new(statuses::status_from_code("503").unwrap());
+    let service = layer.layer(service_fn(|_: http::Request<()>| async {
+        Ok::<_, Infallible>(http::Response::new(()))
+    }));
+
+    let response = futures_executor::block_on(service.oneshot(http::Request::new(()))).unwrap();
+
+    assert_eq!(http::StatusCode::SERVICE_UNAVAILABLE, response.status());
+}
@@ -1,3 +1,6 @@
+// The baseline import is kept as is; clippy considers it redundant.
+#![allow(clippy::single_component_path_imports)]
+
 use statuses;
 
 #[test]
@@ -9,3 +12,1338 @@ fn it_has_message() {
 fn it_has_code() {
     assert_eq!("403", statuses::code("Forbidden"));
 }
+
+#[test]
+fn it_has_markdown_table() {
+    let table = statuses::markdown_table();
+
+    assert!(table.starts_with("| Code | Message | Class |\n"));
+    assert!(table.contains("| 404 | Not Found | Client Error |"));
+}
+
+#[test]
+fn it_returns_previous_registration() {
+    let previous = statuses::register_returning("650", "First Registration").unwrap();
+
+    assert_eq!(None, previous);
+    assert_eq!("First Registration", statuses::message("650"));
+
+    let overridden = statuses::register_returning("650", "Second Registration").unwrap();
+
+    assert_eq!("First Registration", overridden.unwrap().message);
+    assert_eq!("650", statuses::code("Second Registration"));
+}
+
+#[test]
+fn it_coerces_messy_codes() {
+    assert_eq!(Some(404), statuses::coerce_code("HTTP/1.1 404 Not Found"));
+    assert_eq!(Some(503), statuses::coerce_code("  status=503;"));
+    assert_eq!(Some(200), statuses::coerce_code("200.0"));
+    assert_eq!(Some(500), statuses::coerce_code("5000"));
+    assert_eq!(None, statuses::coerce_code("HTTP/1.1"));
+    assert_eq!(None, statuses::coerce_code("999 Unknown"));
+    assert_eq!(None, statuses::coerce_code("Not Found"));
+}
+
+#[test]
+fn it_canonicalizes_message() {
+    assert_eq!(
+        Some("Internal Server Error".to_string()),
+        statuses::canonicalize_message("internal server error")
+    );
+    assert_eq!(None, statuses::canonicalize_message("internal error"));
+}
+
+#[test]
+fn it_validates_file() {
+    let report = statuses::validate_file("codes.json").unwrap();
+
+    assert!(report.is_valid());
+    assert_eq!(report.entries, report.unique_codes);
+}
+
+#[test]
+fn it_reports_invalid_file() {
+    let report = statuses::validate_file("tests/fixtures/bad_codes.json").unwrap();
+
+    assert!(!report.is_valid());
+    assert_eq!(5, report.entries);
+    assert_eq!(4, report.unique_codes);
+    assert_eq!(vec!["200"], report.duplicate_codes);
+    assert_eq!(vec!["999"], report.out_of_range_codes);
+    assert_eq!(vec!["not found"], report.message_collisions);
+
+    let json = serde_json::to_string(&report).unwrap();
+
+    assert!(json.contains("\"duplicate_codes\":[\"200\"]"));
+}
+
+#[test]
+fn it_fails_to_validate_missing_file() {
+    assert!(matches!(
+        statuses::validate_file("tests/fixtures/missing.json"),
+        Err(statuses::StatusError::Io(_))
+    ));
+}
+
+#[test]
+fn it_suggests_retry_after() {
+    assert!(statuses::suggests_retry_after("429"));
+    assert!(statuses::suggests_retry_after("503"));
+    assert!(!statuses::suggests_retry_after("200"));
+}
+
+#[test]
+fn it_describes_status() {
+    let description = statuses::describe("404").unwrap();
+
+    assert_eq!(
+        statuses::StatusDescription {
+            code: 404,
+            message: "Not Found".to_string(),
+            class: statuses::StatusClass::ClientError,
+            is_error: true,
+            is_retryable: false,
+            reference: None,
+            description: None,
+        },
+        description
+    );
+    assert!(matches!(
+        statuses::describe("999"),
+        Err(statuses::StatusError::NotFound(_))
+    ));
+}
+
+#[test]
+fn it_classifies_custom_codes() {
+    statuses::register("642", "Application Signal").unwrap();
+
+    assert_eq!(
+        Some(statuses::StatusClass::Custom(6)),
+        statuses::class("642")
+    );
+    assert!(!statuses::is_server_error("642"));
+    assert!(!statuses::is_error("642"));
+}
+
+#[test]
+fn it_gives_the_digit_of_a_class() {
+    assert_eq!(4, statuses::StatusClass::ClientError.digit());
+    assert_eq!(6, statuses::StatusClass::Custom(6).digit());
+}
+
+struct Teapot;
+
+impl statuses::StatusLookup for Teapot {
+    fn message(&self, _code: &str) -> Option<String> {
+        Some("I'm a Teapot".to_string())
+    }
+
+    fn code(&self, _message: &str) -> Option<String> {
+        Some("418".to_string())
+    }
+}
+
+#[test]
+fn it_looks_up_through_trait_objects() {
+    let registry =
+        statuses::StatusRegistry::from_json(r#"[{"code": "404", "message": "Not Found"}]"#);
+    let lookups: Vec<Box<dyn statuses::StatusLookup>> = vec![
+        Box::new(statuses::GlobalRegistry),
+        Box::new(registry),
+        Box::new(Teapot),
+    ];
+
+    assert_eq!(Some("Not Found".to_string()), lookups[0].message("404"));
+    assert_eq!(Some("404".to_string()), lookups[1].code("not found"));
+    assert_eq!(None, lookups[1].message("200"));
+    assert_eq!(Some("418".to_string()), lookups[2].code("Not Found"));
+}
+
+#[test]
+fn it_lists_missing_and_extra_codes() {
+    assert_eq!(
+        vec![299, 420],
+        statuses::missing_from(&[420, 200, 404, 299])
+    );
+    assert!(statuses::missing_from(&[200, 404]).is_empty());
+
+    let extra = statuses::extra_beyond(&[200, 404]);
+
+    assert!(extra.contains(&500));
+    assert!(!extra.contains(&200));
+    assert!(!extra.contains(&404));
+}
+
+#[test]
+fn it_orders_all_codes_and_messages() {
+    let codes = statuses::all_codes();
+    let numeric: Vec<u16> = codes.iter().map(|code| code.parse().unwrap()).collect();
+    let mut sorted = numeric.clone();
+
+    sorted.sort();
+
+    assert_eq!(sorted, numeric);
+    assert_eq!("100", codes[0]);
+
+    let messages = statuses::all_messages();
+    let mut sorted = messages.clone();
+
+    sorted.sort_by_key(|message| message.to_lowercase());
+
+    assert_eq!(sorted, messages);
+    assert_eq!("Accepted", messages[0]);
+}
+
+#[test]
+fn it_parses_status_line_bytes() {
+    let status = statuses::parse_status_line_bytes(b"HTTP/1.1 200 OK\r\n").unwrap();
+
+    assert_eq!("200", status.code);
+    assert_eq!("OK", status.message);
+    assert_eq!(
+        "503",
+        statuses::parse_status_line_bytes(b"HTTP/2 503\n")
+            .unwrap()
+            .code
+    );
+}
+
+#[test]
+fn it_rejects_malformed_status_line_bytes() {
+    for line in [
+        &b"200 OK\r\n"[..],
+        b"HTTP/1.1 20 OK",
+        b"HTTP/1.1 2000",
+        b"",
+        b"\xff\xfe",
+    ] {
+        assert!(matches!(
+            statuses::parse_status_line_bytes(line),
+            Err(statuses::StatusError::MalformedStatusLine(_))
+        ));
+    }
+
+    assert!(matches!(
+        statuses::parse_status_line_bytes(b"HTTP/1.1 299 Custom\r\n"),
+        Err(statuses::StatusError::NotFound(_))
+    ));
+}
+
+#[test]
+fn it_loads_iana_csv() {
+    let csv = r#"Value,Description,Reference
+100,Continue,"[RFC9110, Section 15.2.1]"
+102,Processing,[RFC2518]
+104-199,Unassigned,
+306,(Unused),"[RFC9110, Section 15.4.7]"
+418,(Unused),"[RFC9110, Section 15.5.19]"
+429,Too Many Requests,"[RFC6585, Section 4]"
+"#;
+    let registry = statuses::from_iana_csv(csv).unwrap();
+
+    assert_eq!(3, registry.len());
+    assert_eq!(Some("Continue"), registry.message("100"));
+    assert_eq!(Some("429"), registry.code("Too Many Requests"));
+    assert_eq!(
+        Some("[RFC6585, Section 4]"),
+        registry.get("429").unwrap().reference.as_deref()
+    );
+    assert_eq!(None, registry.get("306"));
+}
+
+#[test]
+fn it_rejects_invalid_iana_csv() {
+    assert!(statuses::from_iana_csv("code,message\n200,OK\n").is_err());
+    assert!(statuses::from_iana_csv("Value,Description,Reference\nabc,OK,\n").is_err());
+}
+
+#[test]
+fn it_rejects_codes_outside_allow_list() {
+    let json = std::fs::read_to_string("codes.json").unwrap();
+    let registry = statuses::StatusRegistry::from_json(&json).with_allowed(&[200, 404, 500]);
+
+    assert!(registry.is_valid_code("404"));
+    assert!(registry.is_allowed_code("404"));
+    assert!(!registry.is_valid_code("403"));
+    assert!(!registry.is_allowed_code("403"));
+    assert_eq!(None, registry.message("403"));
+    assert_eq!(None, registry.code("Forbidden"));
+    assert_eq!(3, registry.len());
+    assert!(statuses::is_valid_code("403"));
+}
+
+#[test]
+fn it_has_canonical_code_string() {
+    assert_eq!(
+        Some("404".to_string()),
+        statuses::canonical_code_string(404)
+    );
+    assert_eq!(None, statuses::canonical_code_string(420));
+}
+
+#[test]
+fn it_has_code_u16() {
+    assert_eq!(Ok(404), statuses::code_u16("404"));
+    assert_eq!(Ok(200), statuses::code_u16(" 200 "));
+    assert!(matches!(
+        statuses::code_u16("420"),
+        Err(statuses::StatusError::NotFound(_))
+    ));
+}
+
+#[test]
+fn it_rejects_huge_and_malformed_numbers() {
+    assert!(matches!(
+        statuses::code_u16("999999999999"),
+        Err(statuses::StatusError::OutOfRange(_))
+    ));
+    assert!(matches!(
+        statuses::code_u16("65536"),
+        Err(statuses::StatusError::OutOfRange(_))
+    ));
+
+    for input in ["-1", "+404", "٤٠٤", "４０４", "", "4 04", "404.0"] {
+        assert!(matches!(
+            statuses::code_u16(input),
+            Err(statuses::StatusError::InvalidCode(_))
+        ));
+    }
+
+    assert_eq!(None, statuses::coerce_code("999999999999"));
+    assert!(!statuses::is_valid_code("999999999999"));
+    assert!(statuses::describe("-1").is_err());
+}
+
+#[test]
+fn it_has_default_reason() {
+    assert_eq!("Not Found", statuses::default_reason("404"));
+    assert_eq!("Client Error", statuses::default_reason("499"));
+    assert_eq!("Unknown Status", statuses::default_reason("abc"));
+}
+
+#[test]
+fn it_uses_class_fallback() {
+    statuses::set_class_fallback(statuses::StatusClass::ServerError, "Service Unavailable")
+        .unwrap();
+
+    assert_eq!("Service Unavailable", statuses::default_reason("599"));
+    assert_eq!("Internal Server Error", statuses::default_reason("500"));
+    assert_eq!(
+        "Service Unavailable",
+        statuses::generic_reason(statuses::StatusClass::ServerError)
+    );
+}
+
+#[test]
+fn it_separates_builtin_and_custom_statuses() {
+    statuses::register("298", "Custom Origin").unwrap();
+
+    let custom = statuses::custom_statuses();
+    let builtin = statuses::builtin_statuses();
+
+    assert!(custom.iter().any(|status| status.code == "298"));
+    assert!(!builtin.iter().any(|status| status.code == "298"));
+    assert!(builtin.iter().any(|status| status.code == "404"));
+    assert!(!custom.iter().any(|status| status.code == "404"));
+}
+
+#[test]
+fn it_chains_filtered_registries() {
+    let json = std::fs::read_to_string("codes.json").unwrap();
+    let mut registry = statuses::StatusRegistry::from_json(&json);
+
+    registry.register(statuses::Status::new("499", "Client Closed Request"));
+
+    let client_errors = registry
+        .only_class(statuses::StatusClass::ClientError)
+        .excluding(&[451]);
+
+    assert_eq!(Some("Not Found"), client_errors.message("404"));
+    assert_eq!(None, client_errors.message("451"));
+    assert_eq!(None, client_errors.message("500"));
+    assert!(client_errors.is_custom("499"));
+    assert!(client_errors
+        .statuses()
+        .iter()
+        .all(|status| status.code.starts_with('4')));
+    assert_eq!(None, client_errors.only_standard().message("499"));
+}
+
+#[test]
+fn it_has_data_version() {
+    assert!(!statuses::data_version().is_empty());
+    assert_eq!(statuses::DATA_VERSION, statuses::data_version());
+}
+
+#[test]
+fn it_checks_bijectivity() {
+    let bijective = statuses::StatusRegistry::from_json(
+        r#"[{"code": "200", "message": "OK"}, {"code": "404", "message": "Not Found"}]"#,
+    );
+    let shared = statuses::StatusRegistry::from_json(
+        r#"[{"code": "503", "message": "Unavailable"}, {"code": "599", "message": "unavailable"}]"#,
+    );
+
+    assert!(bijective.is_bijective());
+    assert!(!shared.is_bijective());
+    assert!(statuses::is_bijective());
+}
+
+#[test]
+fn it_loads_registry_from_file() {
+    let registry = statuses::StatusRegistry::from_file("codes.json").unwrap();
+
+    assert_eq!(Some("Not Found"), registry.message("404"));
+    assert!(statuses::StatusRegistry::from_json_bytes(b"[{\"code\": ").is_err());
+}
+
+#[test]
+fn it_reports_invalid_encoding() {
+    let bytes = b"[{\"code\": \"200\", \"message\": \"O\xe9K\"}]";
+
+    assert_eq!(
+        Some(statuses::StatusError::Encoding { offset: 30 }),
+        statuses::StatusRegistry::from_json_bytes(bytes).err()
+    );
+
+    let path = std::env::temp_dir().join("statuses-invalid-encoding.json");
+
+    std::fs::write(&path, bytes).unwrap();
+
+    assert_eq!(
+        Some(statuses::StatusError::Encoding { offset: 30 }),
+        statuses::validate_file(&path).err()
+    );
+    assert_eq!(
+        Some(statuses::StatusError::Encoding { offset: 30 }),
+        statuses::StatusRegistry::from_file(&path).err()
+    );
+}
+
+#[test]
+fn it_builds_status() {
+    let status = statuses::Status::builder()
+        .code("404")
+        .message("Not Found")
+        .description("The server cannot find the requested resource.")
+        .reference("RFC 9110, Section 15.5.5")
+        .build();
+
+    assert_eq!(
+        statuses::Status {
+            code: "404".to_string(),
+            message: "Not Found".to_string(),
+            description: Some("The server cannot find the requested resource.".to_string()),
+            reference: Some("RFC 9110, Section 15.5.5".to_string()),
+            aliases: Vec::new(),
+            tags: Vec::new(),
+            introduced_in: None,
+        },
+        status
+    );
+    assert_eq!(
+        statuses::Status::new("404", "Not Found"),
+        statuses::Status::builder()
+            .code("404")
+            .message("Not Found")
+            .build()
+    );
+}
+
+#[test]
+fn it_maps_log_levels() {
+    assert_eq!("debug", statuses::log_level("100"));
+    assert_eq!("info", statuses::log_level("200"));
+    assert_eq!("info", statuses::log_level("301"));
+    assert_eq!("warn", statuses::log_level("404"));
+    assert_eq!("error", statuses::log_level("500"));
+    assert_eq!("warn", statuses::log_level("299"));
+}
+
+#[test]
+fn it_generates_match_arms() {
+    let arms = statuses::generate_match_arms();
+
+    assert!(arms.contains("\"404\" => \"Not Found\",\n"));
+    assert!(arms.starts_with("\"100\" => \"Continue\",\n"));
+    assert!(arms.lines().all(|arm| arm.ends_with(',')));
+}
+
+#[test]
+fn it_lists_unassigned_codes_in_range() {
+    let mut expected: Vec<u16> = (209..=225).collect();
+
+    expected.extend([227, 228, 229]);
+
+    assert_eq!(expected, statuses::unassigned_in_range(200, 229));
+    assert!(statuses::unassigned_in_range(100, 103).is_empty());
+    assert!(statuses::unassigned_in_range(500, 400).is_empty());
+}
+
+#[test]
+fn it_displays_and_parses_status() {
+    let status = statuses::Status::new("404", "Not Found");
+
+    assert_eq!("404 Not Found", status.to_string());
+    assert_eq!(Ok(status), "404 Not Found".parse());
+    assert_eq!(
+        Ok(statuses::Status::builder()
+            .code("503")
+            .message("Service Unavailable")
+            .introduced_in("RFC 1945")
+            .build()),
+        "503".parse()
+    );
+    assert!("Not Found".parse::<statuses::Status>().is_err());
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct LogRecord {
+    #[serde(with = "statuses::as_status_line")]
+    status: statuses::Status,
+    line: statuses::StatusLine,
+}
+
+#[test]
+fn it_round_trips_status_lines_through_serde() {
+    let record = LogRecord {
+        status: statuses::Status::new("404", "Not Found"),
+        line: statuses::StatusLine(statuses::Status::new("200", "OK")),
+    };
+    let json = serde_json::to_string(&record).unwrap();
+
+    assert_eq!(r#"{"status":"404 Not Found","line":"200 OK"}"#, json);
+    assert_eq!(record, serde_json::from_str(&json).unwrap());
+    assert!(serde_json::from_str::<LogRecord>(r#"{"status":"Nope","line":"200 OK"}"#).is_err());
+}
+
+#[test]
+fn it_classifies_redirect_permanence() {
+    for code in ["301", "308"] {
+        assert!(statuses::is_permanent_redirect(code));
+        assert!(!statuses::is_temporary_redirect(code));
+    }
+
+    for code in ["302", "307"] {
+        assert!(statuses::is_temporary_redirect(code));
+        assert!(!statuses::is_permanent_redirect(code));
+    }
+
+    assert!(!statuses::is_permanent_redirect("200"));
+    assert!(!statuses::is_temporary_redirect("304"));
+}
+
+#[test]
+fn it_checks_whether_body_is_allowed() {
+    assert!(!statuses::allows_body("204"));
+    assert!(!statuses::allows_body("304"));
+    assert!(!statuses::allows_body("100"));
+    assert!(statuses::allows_body("200"));
+    assert!(statuses::allows_body("404"));
+    assert!(!statuses::allows_body("299"));
+}
+
+#[test]
+fn it_lists_active_features() {
+    let features = statuses::active_features();
+
+    assert_eq!(cfg!(feature = "http"), features.contains(&"http"));
+    assert_eq!(cfg!(feature = "json5"), features.contains(&"json5"));
+
+    #[cfg(not(any(
+        feature = "bincode",
+        feature = "color",
+        feature = "http",
+        feature = "json5",
+        feature = "jsonschema",
+        feature = "no_hashmap",
+        feature = "phonetic",
+        feature = "regex",
+        feature = "serde_json",
+        feature = "sha2",
+        feature = "tower",
+        feature = "tracing"
+    )))]
+    assert!(features.is_empty());
+}
+
+#[test]
+fn it_suggests_closest_message() {
+    assert_eq!(
+        Some("Internal Server Error".to_string()),
+        statuses::suggest_message("Intrnal Server Eror")
+    );
+    assert_eq!(
+        Some("Not Found".to_string()),
+        statuses::suggest_message(" not fuond ")
+    );
+    assert_eq!(None, statuses::suggest_message("Completely Different"));
+}
+
+#[test]
+fn it_rebuilds_suggestions_after_register() {
+    assert_eq!(None, statuses::suggest_message("Quantum Entanglment"));
+
+    statuses::register("643", "Quantum Entanglement").unwrap();
+
+    assert_eq!(
+        Some("Quantum Entanglement".to_string()),
+        statuses::suggest_message("Quantum Entanglment")
+    );
+}
+
+#[test]
+fn it_returns_every_code_sharing_a_message() {
+    let registry = statuses::StatusRegistry::from_json(
+        r#"[
+            {"code": "503", "message": "Service Unavailable"},
+            {"code": "529", "message": "Service Unavailable"},
+            {"code": "404", "message": "Not Found"}
+        ]"#,
+    );
+
+    assert_eq!(
+        vec!["503".to_string(), "529".to_string()],
+        registry.codes_for_message("service unavailable")
+    );
+    assert_eq!(Some("529"), registry.code("Service Unavailable"));
+    assert_eq!(
+        vec!["404".to_string()],
+        statuses::codes_for_message("Not Found")
+    );
+    assert!(statuses::codes_for_message("Missing").is_empty());
+}
+
+#[test]
+fn it_warns_about_deprecated_codes() {
+    let mut warning = None;
+    let message = statuses::message_with_warning("305", |text| warning = Some(text.to_string()));
+
+    assert_eq!(Ok("Use Proxy".to_string()), message);
+    assert_eq!(Some("305 Use Proxy is deprecated".to_string()), warning);
+
+    let mut warned = false;
+    let message = statuses::message_with_warning("200", |_| warned = true);
+
+    assert_eq!(Ok("OK".to_string()), message);
+    assert!(!warned);
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("999".to_string())),
+        statuses::message_with_warning("999", |_| {})
+    );
+}
+
+#[test]
+fn it_resolves_code_or_message() {
+    let not_found = statuses::Status::builder()
+        .code("404")
+        .message("Not Found")
+        .introduced_in("RFC 1945")
+        .build();
+
+    assert_eq!(Ok(not_found.clone()), statuses::resolve("404"));
+    assert_eq!(Ok(not_found.clone()), statuses::resolve("Not Found"));
+    assert_eq!(Ok(not_found), statuses::resolve(" not found "));
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("999".to_string())),
+        statuses::resolve("999")
+    );
+}
+
+#[test]
+fn it_summarizes_semantics() {
+    assert_eq!(
+        Ok(statuses::Semantics {
+            is_retryable: false,
+            allows_body: true,
+            is_cacheable_by_default: true,
+            is_final: true,
+            permanent_redirect: Some(true),
+        }),
+        statuses::semantics("301")
+    );
+    assert_eq!(
+        Ok(statuses::Semantics {
+            is_retryable: false,
+            allows_body: false,
+            is_cacheable_by_default: true,
+            is_final: true,
+            permanent_redirect: None,
+        }),
+        statuses::semantics("204")
+    );
+    assert!(!statuses::semantics("100").unwrap().is_final);
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("999".to_string())),
+        statuses::semantics("999")
+    );
+}
+
+#[test]
+fn it_classifies_numeric_codes_without_the_table() {
+    assert_eq!(
+        Some(statuses::StatusClass::Informational),
+        statuses::class_u16(199)
+    );
+    assert_eq!(
+        Some(statuses::StatusClass::Success),
+        statuses::class_u16(200)
+    );
+    assert_eq!(
+        Some(statuses::StatusClass::Success),
+        statuses::class_u16(299)
+    );
+    assert_eq!(None, statuses::class("299"));
+    assert_eq!(None, statuses::class_u16(99));
+    assert_eq!(None, statuses::class_u16(u16::MAX));
+}
+
+#[test]
+fn it_resolves_message_aliases() {
+    assert_eq!("203", statuses::code("Non Authoritative Information"));
+    assert_eq!("203", statuses::code("non authoritative information"));
+    assert_eq!("203", statuses::code("Non-Authoritative Information"));
+    assert_eq!(
+        Some("Non-Authoritative Information".to_string()),
+        statuses::canonicalize_message("Non Authoritative Information")
+    );
+
+    let mut registry = statuses::StatusRegistry::from_json(
+        r#"[{"code": "429", "message": "Too Many Requests", "aliases": ["Rate Limited"]}]"#,
+    );
+
+    assert_eq!(Some("429"), registry.code("rate limited"));
+
+    registry.insert(statuses::Status::new("429", "Too Many Requests"));
+
+    assert_eq!(None, registry.code("rate limited"));
+}
+
+#[test]
+fn it_exports_env_lines() {
+    let lines = statuses::to_env_lines();
+
+    assert!(lines.starts_with("STATUS_100=Continue\n"));
+    assert!(lines.contains("\nSTATUS_200=OK\n"));
+    assert!(lines.contains("\nSTATUS_404='Not Found'\n"));
+}
+
+#[test]
+fn it_parses_data_with_byte_order_mark() {
+    let json = "\u{FEFF}[{\"code\": \"200\", \"message\": \"OK\"}]";
+
+    assert_eq!(
+        Some("OK"),
+        statuses::StatusRegistry::from_json(json).message("200")
+    );
+    assert_eq!(
+        Some("OK"),
+        statuses::StatusRegistry::from_json_bytes(json.as_bytes())
+            .unwrap()
+            .message("200")
+    );
+    assert_eq!(
+        Some("OK"),
+        statuses::StatusRegistry::from_json_strict(json)
+            .unwrap()
+            .message("200")
+    );
+}
+
+#[test]
+fn it_converts_between_compact_and_full_statuses() {
+    let compact = statuses::compact_statuses();
+    let not_found = compact.iter().find(|status| status.code == 404).unwrap();
+
+    assert_eq!("Not Found", not_found.message);
+    assert_eq!(
+        statuses::Status::new("404", "Not Found"),
+        statuses::Status::from(not_found.clone())
+    );
+    assert_eq!(
+        Ok(not_found.clone()),
+        statuses::CompactStatus::try_from(&statuses::Status::new("404", "Not Found"))
+    );
+    assert!(statuses::CompactStatus::try_from(&statuses::Status::new("4o4", "Not Found")).is_err());
+    assert!(matches!(
+        not_found.message,
+        statuses::CompactMessage::Shipped(_)
+    ));
+    assert!(std::ptr::eq(
+        not_found.message.as_str(),
+        statuses::canonical_reason_phrase("404").unwrap()
+    ));
+
+    let renamed =
+        statuses::CompactStatus::try_from(&statuses::Status::new("404", "Page Missing")).unwrap();
+
+    assert_eq!("Page Missing", renamed.message);
+    assert!(matches!(
+        renamed.message,
+        statuses::CompactMessage::Shared(_)
+    ));
+}
+
+#[test]
+fn it_decides_whether_requests_are_safe_to_replay() {
+    assert!(statuses::is_safe_to_replay("GET", "503"));
+    assert!(statuses::is_safe_to_replay("PUT", "504"));
+    assert!(statuses::is_safe_to_replay("POST", "429"));
+    assert!(!statuses::is_safe_to_replay("POST", "500"));
+    assert!(!statuses::is_safe_to_replay("GET", "404"));
+    assert!(!statuses::is_safe_to_replay("get", "503"));
+    assert!(!statuses::is_safe_to_replay("GET", "999"));
+}
+
+#[test]
+fn it_shares_message_allocations() {
+    let first = statuses::message_arc("404").unwrap();
+    let second = statuses::message_arc(" 404 ").unwrap();
+
+    assert_eq!("Not Found", &*first);
+    assert!(std::sync::Arc::ptr_eq(&first, &second));
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("999".to_string())),
+        statuses::message_arc("999")
+    );
+}
+
+#[test]
+fn it_explains_codes() {
+    assert_eq!(
+        Ok(
+            "429 Too Many Requests (Client Error): you've hit a rate limit by sending too many \
+            requests. Wait and retry, honoring Retry-After."
+                .to_string()
+        ),
+        statuses::explain("429")
+    );
+    assert!(statuses::explain("507")
+        .unwrap()
+        .contains("the server failed to fulfil a valid request"));
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("999".to_string())),
+        statuses::explain("999")
+    );
+}
+
+#[test]
+fn it_asserts_the_class_of_a_code() {
+    assert_eq!(
+        Ok(()),
+        statuses::assert_class("204", statuses::StatusClass::Success)
+    );
+
+    let err = statuses::assert_class("404", statuses::StatusClass::Success).unwrap_err();
+
+    assert_eq!(
+        statuses::StatusError::ClassMismatch {
+            code: "404".to_string(),
+            expected: statuses::StatusClass::Success,
+            found: statuses::StatusClass::ClientError,
+        },
+        err
+    );
+    assert_eq!(
+        "status `404` is Client Error, expected Success",
+        err.to_string()
+    );
+}
+
+#[test]
+fn it_filters_codes_by_tag() {
+    assert_eq!(
+        vec![
+            "401".to_string(),
+            "403".to_string(),
+            "407".to_string(),
+            "511".to_string()
+        ],
+        statuses::codes_with_tag("auth")
+    );
+    assert_eq!(vec!["rate-limit".to_string()], statuses::tags("429"));
+    assert!(statuses::tags("200").is_empty());
+
+    let registry = statuses::StatusRegistry::from_json(
+        r#"[
+            {"code": "400", "message": "Bad Request", "tags": ["client-fixable"]},
+            {"code": "422", "message": "Unprocessable Content", "tags": ["Client-Fixable"]},
+            {"code": "500", "message": "Internal Server Error"}
+        ]"#,
+    );
+
+    assert_eq!(
+        vec!["400".to_string(), "422".to_string()],
+        registry.codes_with_tag("client-fixable")
+    );
+}
+
+#[test]
+fn it_falls_back_for_unknown_messages() {
+    let mut called = false;
+
+    assert_eq!(
+        "Not Found",
+        statuses::message_or_else("404", |_| {
+            called = true;
+            String::new()
+        })
+    );
+    assert!(!called);
+    assert_eq!(
+        "Unknown status 999",
+        statuses::message_or_else(" 999 ", |code| format!("Unknown status {}", code))
+    );
+    assert_eq!("Unknown", statuses::message_or("999", "Unknown"));
+    assert_eq!("OK", statuses::message_or("200", "Unknown"));
+}
+
+#[test]
+fn it_maps_variant_names_to_codes() {
+    assert_eq!(
+        Some("404".to_string()),
+        statuses::code_from_variant_name("NotFound")
+    );
+    assert_eq!(
+        Some("404".to_string()),
+        statuses::code_from_variant_name("NOT_FOUND")
+    );
+    assert_eq!(
+        Some("203".to_string()),
+        statuses::code_from_variant_name("NonAuthoritativeInformation")
+    );
+    assert_eq!(None, statuses::code_from_variant_name("NotAStatus"));
+    assert_eq!(None, statuses::code_from_variant_name("__"));
+}
+
+#[test]
+fn it_covers_the_iana_registry() {
+    assert!(statuses::iana_coverage() > 0.95);
+    assert!(statuses::iana_coverage() <= 1.0);
+    assert_eq!(Vec::<u16>::new(), statuses::missing_iana_codes());
+}
+
+#[test]
+fn it_looks_up_optionally() {
+    assert_eq!(Some("Not Found".to_string()), statuses::message_opt("404"));
+    assert_eq!(None, statuses::message_opt("999"));
+    assert_eq!(Some("404".to_string()), statuses::code_opt("Not Found"));
+    assert_eq!(None, statuses::code_opt("Missing"));
+}
+
+#[test]
+fn it_lists_codes_by_introducing_spec() {
+    assert_eq!(
+        vec![
+            "428".to_string(),
+            "429".to_string(),
+            "431".to_string(),
+            "511".to_string()
+        ],
+        statuses::codes_from_spec("RFC 6585")
+    );
+    assert_eq!(
+        Some("RFC 1945".to_string()),
+        statuses::resolve("404").unwrap().introduced_in
+    );
+    assert!(statuses::codes_from_spec("RFC 0").is_empty());
+}
+
+#[test]
+fn it_rejects_non_canonical_codes_in_strict_lookup() {
+    assert_eq!(
+        Ok("OK".to_string()),
+        statuses::message_strict_numeric("200")
+    );
+
+    for code in ["0200", " 200", "200 ", "200.0", "+200", "20"] {
+        assert_eq!(
+            Err(statuses::StatusError::NonCanonicalCode(code.to_string())),
+            statuses::message_strict_numeric(code)
+        );
+    }
+
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("999".to_string())),
+        statuses::message_strict_numeric("999")
+    );
+}
+
+#[test]
+fn it_compares_codes_for_retry_purposes() {
+    assert!(statuses::retry_equivalent("502", "503"));
+    assert!(statuses::retry_equivalent("400", "404"));
+    assert!(!statuses::retry_equivalent("500", "400"));
+    assert!(!statuses::retry_equivalent("500", "501"));
+    assert!(!statuses::retry_equivalent("999", "999"));
+}
+
+#[test]
+fn it_groups_statuses_by_class() {
+    let groups = statuses::group_by_class();
+    let client_errors = &groups[&statuses::StatusClass::ClientError];
+
+    assert_eq!(
+        statuses::StatusClass::Informational,
+        *groups.keys().next().unwrap()
+    );
+    assert_eq!("400", client_errors[0].code);
+    assert!(client_errors
+        .iter()
+        .all(|status| status.code.starts_with('4')));
+}
+
+#[test]
+fn it_renders_tree_view() {
+    let tree = statuses::tree_view();
+    let client_errors = tree.find("4xx Client Error\n").unwrap();
+    let server_errors = tree.find("5xx Server Error\n").unwrap();
+    let not_found = tree.find("\n  404 Not Found\n").unwrap();
+
+    assert!(tree.starts_with("1xx Informational\n  100 Continue\n"));
+    assert!(client_errors < not_found && not_found < server_errors);
+    assert_eq!(tree, statuses::tree_view());
+}
+
+#[test]
+fn it_lists_mandatory_client_codes() {
+    let codes = statuses::mandatory_client_codes();
+
+    assert!(codes.contains(&400));
+    assert!(codes.contains(&500));
+    assert!(statuses::missing_from(&codes).is_empty());
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct FaultConfig {
+    #[serde(with = "statuses::serde::status_code")]
+    status: u16,
+    #[serde(with = "statuses::serde::status_code_lenient")]
+    fallback: u16,
+}
+
+#[test]
+fn it_validates_status_code_fields_through_serde() {
+    let config: FaultConfig = serde_json::from_str(r#"{"status": 503, "fallback": 999}"#).unwrap();
+
+    assert_eq!(
+        FaultConfig {
+            status: 503,
+            fallback: 999
+        },
+        config
+    );
+    assert_eq!(
+        r#"{"status":503,"fallback":999}"#,
+        serde_json::to_string(&config).unwrap()
+    );
+
+    let err =
+        serde_json::from_str::<FaultConfig>(r#"{"status": 999, "fallback": 500}"#).unwrap_err();
+
+    assert!(err.to_string().contains("unknown status code 999"));
+}
+
+#[test]
+fn it_classifies_codes_as_assigned_unassigned_or_invalid() {
+    assert_eq!(statuses::CodeStatus::Assigned, statuses::code_status(404));
+    assert_eq!(
+        statuses::CodeStatus::UnassignedInRange,
+        statuses::code_status(499)
+    );
+    assert_eq!(statuses::CodeStatus::Invalid, statuses::code_status(99));
+    assert_eq!(statuses::CodeStatus::Invalid, statuses::code_status(1000));
+}
+
+#[test]
+fn it_overrides_a_message_in_a_registry() {
+    let json = std::fs::read_to_string("codes.json").unwrap();
+    let mut registry = statuses::StatusRegistry::from_json(&json);
+    let len = registry.len();
+
+    assert_eq!(Ok(()), registry.set_message("404", "Page Not Found"));
+    assert_eq!(Some("Page Not Found"), registry.message("404"));
+    assert_eq!(Some("404"), registry.code("Page Not Found"));
+    assert_eq!(None, registry.code("Not Found"));
+    assert_eq!(len, registry.len());
+    assert!(!registry.is_custom("404"));
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("499".to_string())),
+        registry.set_message("499", "Client Closed Request")
+    );
+}
+
+#[test]
+fn it_maps_codes_to_exit_codes() {
+    assert_eq!(0, statuses::to_exit_code("200"));
+    assert_eq!(0, statuses::to_exit_code("304"));
+    assert_eq!(1, statuses::to_exit_code("404"));
+    assert_eq!(2, statuses::to_exit_code("503"));
+    assert_eq!(3, statuses::to_exit_code("499"));
+    assert_eq!(3, statuses::to_exit_code("abc"));
+}
+
+#[test]
+fn it_completes_messages_by_prefix() {
+    let messages: Vec<String> = statuses::complete_message("not", 10)
+        .into_iter()
+        .map(|status| status.message)
+        .collect();
+
+    assert!(messages.contains(&"Not Found".to_string()));
+    assert!(messages.contains(&"Not Modified".to_string()));
+    assert!(messages.iter().all(|message| message.starts_with("Not")));
+
+    let codes: Vec<String> = statuses::complete_message("NOT ", 2)
+        .into_iter()
+        .map(|status| status.code)
+        .collect();
+
+    assert_eq!(vec!["304", "404"], codes);
+    assert!(statuses::complete_message("xyz", 10).is_empty());
+}
+
+#[test]
+fn it_exposes_the_normalized_key() {
+    assert_eq!("not found", statuses::debug_normalize("  Not Found\n"));
+    assert_eq!("not  found", statuses::debug_normalize("Not  Found"));
+    assert_eq!(None, statuses::code_opt("Not  Found"));
+}
+
+#[test]
+fn it_returns_the_stored_message() {
+    assert_eq!(
+        Some("Not Found"),
+        statuses::stored_message("404").as_deref()
+    );
+    assert_eq!(
+        "Not Found",
+        statuses::stored_message(" 404 ").unwrap().to_string()
+    );
+    assert!(statuses::stored_message("499").is_none());
+}
+
+#[test]
+fn it_flags_codes_relevant_to_http3() {
+    assert!(statuses::http3_relevant("103"));
+    assert!(statuses::http3_relevant("421"));
+    assert!(!statuses::http3_relevant("404"));
+}
+
+#[test]
+fn it_registers_several_codes_at_once() {
+    let entries = vec![
+        ("645".to_string(), "Bulk One".to_string()),
+        ("646".to_string(), "Bulk Two".to_string()),
+        ("647".to_string(), "Bulk Three".to_string()),
+    ];
+
+    assert_eq!(Ok(3), statuses::register_all(entries.clone()));
+    assert_eq!("Bulk Two", statuses::message("646"));
+    assert_eq!("647", statuses::code("Bulk Three"));
+    assert_eq!(Ok(0), statuses::register_all(entries));
+}
+
+#[test]
+fn it_checks_reasons_are_header_safe() {
+    assert!(statuses::is_header_safe_reason("404"));
+    assert!(!statuses::is_header_safe_reason("499"));
+
+    statuses::register("648", "Split\r\nSet-Cookie: session=1").unwrap();
+
+    assert!(!statuses::is_header_safe_reason("648"));
+}
+
+#[test]
+fn it_formats_codes_to_a_width() {
+    assert_eq!(Ok("0200".to_string()), statuses::code_formatted("OK", 4));
+    assert_eq!(
+        Ok("404".to_string()),
+        statuses::code_formatted("Not Found", 2)
+    );
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("Nope".to_string())),
+        statuses::code_formatted("Nope", 4)
+    );
+}
+
+#[test]
+fn it_tolerates_british_spellings() {
+    assert_eq!(
+        Some("401".to_string()),
+        statuses::code_spelling_tolerant("Unauthorised")
+    );
+    assert_eq!(
+        Some("401".to_string()),
+        statuses::code_spelling_tolerant("unauthorized")
+    );
+    assert_eq!(
+        Some("404".to_string()),
+        statuses::code_spelling_tolerant("Not Found")
+    );
+    assert_eq!(
+        None,
+        statuses::code_spelling_tolerant("Unauthorised Access")
+    );
+}
+
+#[test]
+fn it_returns_the_class_digit() {
+    assert_eq!(Some(4), statuses::class_digit("404"));
+    assert_eq!(Some(2), statuses::class_digit(" 200 "));
+    assert_eq!(None, statuses::class_digit("499"));
+}
+
+#[test]
+fn it_validates_messages() {
+    assert!(statuses::is_valid_message("not found"));
+    assert!(statuses::is_valid_message("Non Authoritative Information"));
+    assert!(!statuses::is_valid_message("Nope"));
+}
+
+#[test]
+fn it_flags_auth_related_codes() {
+    assert!(statuses::is_auth_related("401"));
+    assert!(statuses::is_auth_related("407"));
+    assert!(statuses::is_auth_related("511"));
+    assert!(!statuses::is_auth_related("404"));
+
+    let tagged = statuses::codes_with_tag("auth");
+
+    for code in statuses::all_codes() {
+        assert_eq!(tagged.contains(&code), statuses::is_auth_related(&code));
+    }
+}
+
+#[test]
+fn it_lists_codes_in_a_class() {
+    let codes = statuses::codes_in_class(statuses::StatusClass::Redirection);
+
+    assert_eq!(Some(&300), codes.first());
+    assert!(codes.contains(&304));
+    assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(codes.iter().all(|code| (300..400).contains(code)));
+}
+
+#[test]
+fn it_has_html_table() {
+    let table = statuses::html_table();
+
+    assert!(table.starts_with("<table>\n"));
+    assert!(table.ends_with("</table>\n"));
+    assert!(table.contains(
+        "<tr class=\"status-4xx\"><td>404</td><td>Not Found</td><td>Client Error</td></tr>"
+    ));
+    assert!(table.contains("<td>I&#39;m a Teapot</td>"));
+}
+
+#[test]
+fn it_renders_messages_in_title_case() {
+    statuses::register("649", "early hints for a non-authoritative proxy").unwrap();
+
+    assert_eq!(
+        Ok("Early Hints For a Non-Authoritative Proxy".to_string()),
+        statuses::message_title_case("649")
+    );
+    assert_eq!(
+        Ok("URI Too Long".to_string()),
+        statuses::message_title_case("414")
+    );
+    assert_eq!(
+        Err(statuses::StatusError::NotFound("499".to_string())),
+        statuses::message_title_case("499")
+    );
+}
+
+#[test]
+fn it_detects_ambiguous_input() {
+    let registry = statuses::StatusRegistry::from_json(
+        r#"[
+            {"code": "200", "message": "404"},
+            {"code": "404", "message": "Not Found"}
+        ]"#,
+    );
+
+    assert!(registry.is_ambiguous("404"));
+    assert!(registry.is_ambiguous(" 404 "));
+    assert!(!registry.is_ambiguous("200"));
+    assert!(!registry.is_ambiguous("Not Found"));
+    assert!(!statuses::is_ambiguous("404"));
+}
+
+#[test]
+fn it_caches_status_lines() {
+    let first = statuses::cached_status_line(200).unwrap();
+    let second = statuses::cached_status_line(200).unwrap();
+
+    assert_eq!("200 OK", &*first);
+    assert!(std::sync::Arc::ptr_eq(&first, &second));
+    assert_eq!(
+        Some("404 Not Found"),
+        statuses::cached_status_line(404).as_deref()
+    );
+    assert_eq!(None, statuses::cached_status_line(499));
+}
+
+#[test]
+fn it_knows_when_content_length_is_forbidden() {
+    assert!(statuses::forbids_content_length("204"));
+    assert!(statuses::forbids_content_length("103"));
+    assert!(!statuses::forbids_content_length("200"));
+    assert!(!statuses::forbids_content_length("304"));
+    assert!(!statuses::forbids_content_length("499"));
+}
+
+#[test]
+fn it_keeps_reason_fallbacks_per_registry() {
+    let mut registry = statuses::StatusRegistry::from_json(r#"[{"code": "200", "message": "OK"}]"#);
+
+    registry.set_class_fallback(statuses::StatusClass::ClientError, "Bad Input");
+    registry.set_unknown_reason("Mystery Status");
+
+    assert_eq!("OK", registry.default_reason("200"));
+    assert_eq!("Bad Input", registry.default_reason("404"));
+    assert_eq!("Mystery Status", registry.default_reason("abc"));
+    assert_eq!(
+        "Server Error",
+        registry.generic_reason(statuses::StatusClass::ServerError)
+    );
+    assert_eq!("Unknown Status", statuses::default_reason("abc"));
+}
+
+#[test]
+fn it_keeps_reason_fallbacks_in_filtered_views() {
+    let mut registry = statuses::StatusRegistry::from_json(
+        r#"[{"code": "200", "message": "OK"}, {"code": "404", "message": "Not Found"}]"#,
+    );
+
+    registry.set_class_fallback(statuses::StatusClass::ClientError, "Bad Input");
+    registry.set_unknown_reason("Mystery Status");
+
+    let views = [
+        registry.only_class(statuses::StatusClass::Success),
+        registry.only_standard(),
+        registry.excluding(&[404]),
+    ];
+
+    for view in &views {
+        for code in ["200", "499", "abc"] {
+            assert_eq!(registry.default_reason(code), view.default_reason(code));
+        }
+    }
+}
+
+#[test]
+fn it_keys_codes_as_written() {
+    let registry = statuses::StatusRegistry::from_json(
+        r#"[{"code": "404", "message": "Not Found"}, {"code": "0404", "message": "Padded"}, {"code": "abc", "message": "Letters"}]"#,
+    );
+
+    assert_eq!(Some("Not Found"), registry.message("404"));
+    assert_eq!(Some("Padded"), registry.message("0404"));
+    assert_eq!(Some("Letters"), registry.message("ABC"));
+    assert_eq!(None, registry.message("4040"));
+    assert_eq!(3, registry.len());
+}
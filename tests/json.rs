@@ -0,0 +1,43 @@
+#![cfg(feature = "serde_json")]
+
+use serde_json::json;
+use statuses::Status;
+
+#[test]
+fn it_converts_status_to_value() {
+    let status = Status::new("404", "Not Found");
+
+    assert_eq!(
+        json!({"code": "404", "message": "Not Found"}),
+        status.to_value()
+    );
+    assert_eq!(
+        json!({"code": 404, "message": "Not Found"}),
+        status.to_value_numeric()
+    );
+    assert_eq!(status.to_value(), serde_json::Value::from(&status));
+}
+
+#[test]
+fn it_includes_metadata_in_value() {
+    let status = Status::builder()
+        .code("429")
+        .message("Too Many Requests")
+        .reference("RFC 6585")
+        .build();
+
+    assert_eq!(
+        json!({"code": "429", "message": "Too Many Requests", "reference": "RFC 6585"}),
+        status.to_value()
+    );
+}
+
+#[test]
+fn it_renders_the_table_as_a_map() {
+    let json = statuses::to_json_map().unwrap();
+    let map: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&json).unwrap();
+
+    assert!(json.starts_with(r#"{"100":"Continue","101":"Switching Protocols","#));
+    assert!(json.contains(r#""404":"Not Found""#));
+    assert_eq!(statuses::all_codes().len(), map.len());
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "json5")]
+
+#[test]
+fn it_loads_commented_document() {
+    let json = r#"[
+        // Informational
+        { "code": "100", "message": "Continue" },
+        /* Shipped by some proxies. */
+        { code: "499", message: "Client Closed Request", },
+    ]"#;
+    let registry = statuses::StatusRegistry::from_json_bytes(json.as_bytes()).unwrap();
+
+    assert_eq!(2, registry.len());
+    assert_eq!(Some("Client Closed Request"), registry.message("499"));
+    assert_eq!(Some("100"), registry.code("Continue"));
+}
+
+#[test]
+fn it_validates_commented_file() {
+    let path = std::env::temp_dir().join("statuses-commented.json5");
+
+    std::fs::write(
+        &path,
+        r#"[
+            // Informational
+            { "code": "100", "message": "Continue" },
+            { code: "200", message: "OK", },
+        ]"#,
+    )
+    .unwrap();
+
+    let report = statuses::validate_file(&path).unwrap();
+
+    assert!(report.is_valid());
+    assert_eq!(2, report.entries);
+}
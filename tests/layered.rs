@@ -0,0 +1,30 @@
+// Loading replaces the global table, which would race with the other integration tests.
+
+use std::path::Path;
+
+use statuses::StatusError;
+
+#[test]
+fn it_layers_data_files() {
+    let base = Path::new("codes.json");
+    let overrides = Path::new("tests/fixtures/override_codes.json");
+    let truncated = Path::new("tests/fixtures/truncated_codes.json");
+
+    assert_eq!(Ok(()), statuses::load_layered(&[base, overrides]));
+    assert_eq!("Down For Maintenance", statuses::message("503"));
+    assert_eq!("Network Connect Timeout Error", statuses::message("599"));
+    assert_eq!("Not Found", statuses::message("404"));
+
+    match statuses::load_layered(&[base, truncated]) {
+        Err(StatusError::InvalidData(reason)) => {
+            assert!(reason.starts_with("tests/fixtures/truncated_codes.json: "))
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    assert!(matches!(
+        statuses::load_layered(&[Path::new("missing.json")]),
+        Err(StatusError::Io(reason)) if reason.starts_with("missing.json: ")
+    ));
+    assert_eq!("Down For Maintenance", statuses::message("503"));
+}
@@ -0,0 +1,24 @@
+// Loading replaces the global table, which would race with the other integration tests.
+
+use statuses::StatusClass;
+
+#[test]
+fn it_leaves_non_canonical_codes_out_of_the_classes() {
+    statuses::load_strict_json_str(
+        r#"[
+            {"code": "0404", "message": "Padded"},
+            {"code": "+410", "message": "Signed"},
+            {"code": "200", "message": "OK"}
+        ]"#,
+    )
+    .unwrap();
+
+    assert!(!statuses::is_valid_code("404"));
+    assert!(statuses::codes_in_class(StatusClass::ClientError).is_empty());
+    assert_eq!(vec![StatusClass::Success], statuses::present_classes());
+    assert_eq!(
+        vec![StatusClass::Success],
+        statuses::group_by_class().into_keys().collect::<Vec<_>>()
+    );
+    assert_eq!("2xx Success\n  200 OK\n", statuses::tree_view());
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "phonetic")]
+
+#[test]
+fn it_suggests_phonetically_similar_message() {
+    assert_eq!(
+        Some("Internal Server Error".to_string()),
+        statuses::suggest_message_phonetic("Intrnal Servr Eror")
+    );
+    assert_eq!(
+        Some("Not Found".to_string()),
+        statuses::suggest_message_phonetic("nott fownd")
+    );
+    assert_eq!(
+        None,
+        statuses::suggest_message_phonetic("Completely Different")
+    );
+    assert_eq!(None, statuses::suggest_message_phonetic(""));
+}
@@ -0,0 +1,22 @@
+// Loading replaces the global table, which would race with the other integration tests.
+
+use statuses::StatusClass;
+
+#[test]
+fn it_lists_the_classes_present_in_the_table() {
+    assert_eq!(5, statuses::present_classes().len());
+
+    statuses::load_strict_json_str(
+        r#"[
+            {"code": "404", "message": "Not Found"},
+            {"code": "200", "message": "OK"},
+            {"code": "400", "message": "Bad Request"}
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![StatusClass::Success, StatusClass::ClientError],
+        statuses::present_classes()
+    );
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "regex")]
+
+#[test]
+fn it_finds_statuses_by_message_regex() {
+    let codes: Vec<String> = statuses::find_by_message_regex("^.*Error$")
+        .unwrap()
+        .into_iter()
+        .map(|status| status.code)
+        .collect();
+
+    assert_eq!(vec!["500".to_string()], codes);
+}
+
+#[test]
+fn it_rejects_invalid_regex() {
+    assert!(matches!(
+        statuses::find_by_message_regex("(unclosed"),
+        Err(statuses::StatusError::InvalidPattern(_))
+    ));
+}
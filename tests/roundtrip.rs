@@ -0,0 +1,146 @@
+// Checks every entry of codes.json against the public API. The table is left untouched so the
+// data file is the only source of truth; the other integration tests register codes of their own.
+
+use statuses::StatusClass;
+
+/// One entry of the data file, as read independently of the crate's loader.
+struct Entry {
+    code: String,
+    message: String,
+    numeric: u16,
+}
+
+/// An invariant every entry must satisfy, returning a description of the mismatch otherwise.
+type Invariant = fn(&Entry) -> Result<(), String>;
+
+/// Fails with the left and right values when they differ.
+fn agree<T: PartialEq + std::fmt::Debug>(what: &str, actual: T, expected: T) -> Result<(), String> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: got {:?}, expected {:?}",
+            what, actual, expected
+        ))
+    }
+}
+
+const INVARIANTS: &[(&str, Invariant)] = &[
+    ("message", |entry| {
+        agree(
+            "message",
+            statuses::message(&entry.code),
+            entry.message.clone(),
+        )
+    }),
+    ("code", |entry| {
+        agree(
+            "codes_for_message contains code",
+            statuses::codes_for_message(&entry.message).contains(&entry.code),
+            true,
+        )
+    }),
+    ("is_valid_code", |entry| {
+        agree("is_valid_code", statuses::is_valid_code(&entry.code), true)
+    }),
+    ("is_valid_message", |entry| {
+        agree(
+            "is_valid_message",
+            statuses::is_valid_message(&entry.message),
+            true,
+        )
+    }),
+    ("code_u16", |entry| {
+        agree(
+            "code_u16",
+            statuses::code_u16(&entry.code),
+            Ok(entry.numeric),
+        )
+    }),
+    ("class", |entry| {
+        agree(
+            "class",
+            statuses::class(&entry.code),
+            StatusClass::from_code(entry.numeric),
+        )
+    }),
+    ("class_digit", |entry| {
+        agree(
+            "class_digit",
+            statuses::class_digit(&entry.code),
+            Some((entry.numeric / 100) as u8),
+        )
+    }),
+    ("status_line", |entry| {
+        let status = statuses::resolve(&entry.code).map_err(|err| err.to_string())?;
+
+        agree(
+            "status line",
+            status.to_string(),
+            format!("{} {}", entry.code, entry.message),
+        )
+    }),
+    ("parse_status_line_bytes", |entry| {
+        let line = format!("HTTP/1.1 {} {}\r\n", entry.code, entry.message);
+        let status = statuses::parse_status_line_bytes(line.as_bytes()).map(|s| s.message);
+
+        agree("parsed status line", status, Ok(entry.message.clone()))
+    }),
+    ("stored_message", |entry| {
+        agree(
+            "stored_message",
+            statuses::stored_message(&entry.code).as_deref(),
+            Some(entry.message.as_str()),
+        )
+    }),
+    ("message_title_case", |entry| {
+        agree(
+            "message_title_case",
+            statuses::message_title_case(&entry.code),
+            Ok(entry.message.clone()),
+        )
+    }),
+    ("canonical_reason_phrase", |entry| {
+        agree(
+            "canonical_reason_phrase",
+            statuses::canonical_reason_phrase(&entry.code),
+            Some(entry.message.as_str()),
+        )
+    }),
+];
+
+fn entries() -> Vec<Entry> {
+    let json = std::fs::read_to_string("codes.json").unwrap();
+
+    gjson::parse(&json)
+        .array()
+        .into_iter()
+        .map(|entry| {
+            let code = entry.get("code").to_string();
+
+            Entry {
+                numeric: code.parse().unwrap(),
+                message: entry.get("message").to_string(),
+                code,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn it_agrees_with_the_data_file_for_every_entry() {
+    let entries = entries();
+    let mut failures = Vec::new();
+
+    assert!(!entries.is_empty());
+
+    for entry in &entries {
+        for (name, invariant) in INVARIANTS {
+            if let Err(reason) = invariant(entry) {
+                failures.push(format!("{} [{}]: {}", entry.code, name, reason));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "jsonschema")]
+
+#[test]
+fn it_validates_against_schema() {
+    let json = std::fs::read_to_string("codes.json").unwrap();
+
+    assert_eq!(Ok(()), statuses::validate_against_schema(&json));
+}
+
+#[test]
+fn it_reports_schema_violations() {
+    let json = r#"[{"code": "200"}, {"code": 404, "message": "Not Found"}, {"code": "42", "message": "Answer"}]"#;
+    let error = statuses::validate_against_schema(json)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("\"message\" is a required property"));
+    assert!(error.contains("/1/code"));
+    assert!(error.contains("/2/code"));
+    assert!(statuses::validate_against_schema("not json").is_err());
+}
@@ -0,0 +1,55 @@
+// Installing a source replaces the global table, which would race with the other integration
+// tests.
+
+use statuses::{CodeSource, EmbeddedSource, FileSource, JsonSource, Status, StatusError};
+
+struct VendorSource;
+
+impl CodeSource for VendorSource {
+    fn load(&self) -> Result<Vec<Status>, StatusError> {
+        Ok(vec![
+            Status::new("200", "OK"),
+            Status::new("599", "Vendor Timeout"),
+        ])
+    }
+}
+
+struct FailingSource;
+
+impl CodeSource for FailingSource {
+    fn load(&self) -> Result<Vec<Status>, StatusError> {
+        Err(StatusError::Io("connection refused".to_string()))
+    }
+}
+
+#[test]
+fn it_installs_statuses_from_a_source() {
+    assert_eq!(Ok(()), statuses::init_from_source(VendorSource));
+    assert_eq!("Vendor Timeout", statuses::message("599"));
+    assert!(!statuses::is_valid_code("404"));
+
+    assert_eq!(
+        Err(StatusError::Io("connection refused".to_string())),
+        statuses::init_from_source(FailingSource)
+    );
+    assert_eq!("Vendor Timeout", statuses::message("599"));
+
+    assert_eq!(Ok(()), statuses::init_from_source(EmbeddedSource));
+    assert_eq!("Not Found", statuses::message("404"));
+    assert!(!statuses::is_valid_code("599"));
+}
+
+#[test]
+fn it_loads_builtin_sources() {
+    let embedded = EmbeddedSource.load().unwrap();
+
+    assert_eq!(Ok(embedded), FileSource::new("codes.json").load());
+    assert_eq!(
+        Ok(vec![Status::new("200", "OK")]),
+        JsonSource::new(r#"[{"code": "200", "message": "OK"}]"#).load()
+    );
+    assert!(matches!(
+        FileSource::new("missing.json").load(),
+        Err(StatusError::Io(_))
+    ));
+}
@@ -0,0 +1,38 @@
+// Loading replaces the global table, which would race with the other integration tests.
+
+use statuses::{StatusError, StatusRegistry};
+
+const CLEAN: &str = r#"[{"code": "200", "message": "OK"}]"#;
+const EXTRA: &str = r#"[{"code": "200", "message": "OK", "since": "HTTP/1.0"}]"#;
+
+#[test]
+fn it_tolerates_unknown_fields_by_default() {
+    assert_eq!(Some("OK"), StatusRegistry::from_json(CLEAN).message("200"));
+    assert_eq!(Some("OK"), StatusRegistry::from_json(EXTRA).message("200"));
+}
+
+#[test]
+fn it_rejects_unknown_fields_in_strict_mode() {
+    assert_eq!(
+        Some("OK"),
+        StatusRegistry::from_json_strict(CLEAN)
+            .unwrap()
+            .message("200")
+    );
+    assert!(matches!(
+        StatusRegistry::from_json_strict(EXTRA),
+        Err(StatusError::InvalidData(_))
+    ));
+}
+
+#[test]
+fn it_loads_strict_json_into_the_table() {
+    let json = std::fs::read_to_string("codes.json").unwrap();
+
+    assert!(matches!(
+        statuses::load_strict_json_str(EXTRA),
+        Err(StatusError::InvalidData(_))
+    ));
+    assert_eq!(Ok(()), statuses::load_strict_json_str(&json));
+    assert_eq!("Not Found", statuses::message("404"));
+}
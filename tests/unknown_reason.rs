@@ -0,0 +1,32 @@
+// Configures, reloads and finally freezes the global table, which would race with the other
+// integration tests.
+
+#[test]
+fn it_uses_the_configured_unknown_reason() {
+    assert_eq!("Unknown Status", statuses::default_reason("1000"));
+
+    statuses::set_unknown_reason("Mystery Status").unwrap();
+
+    assert_eq!("Mystery Status", statuses::default_reason("1000"));
+    assert_eq!("Mystery Status", statuses::default_reason("abc"));
+    assert_eq!("Client Error", statuses::default_reason("499"));
+    assert_eq!("Not Found", statuses::default_reason("404"));
+    assert_eq!("fallback", statuses::message_or("1000", "fallback"));
+    assert_eq!("1000", statuses::message_or_else("1000", str::to_string));
+
+    statuses::init_from_source(statuses::EmbeddedSource).unwrap();
+
+    assert_eq!("Mystery Status", statuses::default_reason("1000"));
+
+    statuses::freeze();
+
+    assert_eq!(
+        Err(statuses::StatusError::Frozen),
+        statuses::set_unknown_reason("Unknown")
+    );
+    assert_eq!(
+        Err(statuses::StatusError::Frozen),
+        statuses::set_class_fallback(statuses::StatusClass::ClientError, "Oops")
+    );
+    assert_eq!("Mystery Status", statuses::default_reason("1000"));
+}